@@ -0,0 +1,311 @@
+//! `aoc run --all`: every solver on its default input at once, from a pool of
+//! threads. Each task catches the panics of its solver, so that a broken day
+//! ends up as a row of the table instead of aborting the run.
+
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::Instant,
+};
+
+use serde::Serialize;
+
+use crate::{cache::Entry, registry::Solver};
+
+/// How a task ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    /// The solver or its parser panicked.
+    Panic,
+    /// The solver ends on `todo!()`.
+    Todo,
+    /// The input could not be read.
+    NoInput,
+    /// The parser rejected the input.
+    Invalid,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Status::Ok => "ok",
+            Status::Panic => "panic",
+            Status::Todo => "todo",
+            Status::NoInput => "no input",
+            Status::Invalid => "invalid",
+        };
+        f.pad(s)
+    }
+}
+
+/// One row of the table, one element of the JSON array.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    /// What went wrong, when the status is not ok.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_ns: Option<u64>,
+    /// What the parser recorded with `aoc_common::stats`.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub parse_stats: BTreeMap<String, serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solve_ns: Option<u64>,
+    /// The answer and its timings come from the cache.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub cached: bool,
+    /// What the solver recorded with `aoc_common::stats`.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub stats: BTreeMap<String, serde_json::Value>,
+}
+
+impl Row {
+    fn new(solver: &Solver, status: Status, message: Option<String>) -> Self {
+        Row {
+            day: solver.day,
+            part: solver.part,
+            status,
+            answer: None,
+            message,
+            parse_ns: None,
+            parse_stats: BTreeMap::new(),
+            solve_ns: None,
+            cached: false,
+            stats: BTreeMap::new(),
+        }
+    }
+
+    pub fn cached(solver: &Solver, entry: Entry) -> Self {
+        Row {
+            answer: Some(entry.answer),
+            parse_ns: Some(entry.parse_ns),
+            parse_stats: entry.parse_stats,
+            solve_ns: Some(entry.solve_ns),
+            cached: true,
+            stats: entry.stats,
+            ..Row::new(solver, Status::Ok, None)
+        }
+    }
+
+    /// The answer to cache, when the run found one.
+    pub fn entry(&self) -> Option<Entry> {
+        Some(Entry {
+            answer: self.answer.clone()?,
+            parse_ns: self.parse_ns?,
+            solve_ns: self.solve_ns?,
+            parse_stats: self.parse_stats.clone(),
+            stats: self.stats.clone(),
+        })
+        .filter(|_| self.status == Status::Ok && !self.cached)
+    }
+
+    fn panicked(solver: &Solver, payload: Box<dyn Any + Send>) -> Self {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        let status = if message.starts_with("not yet implemented") {
+            Status::Todo
+        } else {
+            Status::Panic
+        };
+        Row::new(solver, status, Some(message))
+    }
+}
+
+/// `f` of every item from at most `jobs` threads, in the order of the items.
+pub fn map_parallel<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(items.iter().map(|_| None).collect::<Vec<_>>());
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                let result = f(item);
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every item is mapped"))
+        .collect()
+}
+
+/// Parse the input and solve it, or say why there is no input. Each part
+/// parses its own copy so that both parts can run at the same time.
+pub fn run(solver: &Solver, input: &Result<String, String>) -> Row {
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => return Row::new(solver, Status::NoInput, Some(e.clone())),
+    };
+    aoc_common::stats::take();
+    let start = Instant::now();
+    let model = match panic::catch_unwind(|| (solver.parse)(input)) {
+        Ok(Ok(model)) => model,
+        // Only the first line of a parse error, the table has one row per part
+        Ok(Err(e)) => {
+            let message = e.to_string().lines().next().unwrap_or("").to_string();
+            return Row::new(solver, Status::Invalid, Some(message));
+        }
+        Err(payload) => return Row::panicked(solver, payload),
+    };
+    let parsed = Instant::now();
+    let parse_stats = aoc_common::stats::take();
+    let answer = match panic::catch_unwind(AssertUnwindSafe(|| (solver.solve)(model.as_ref()))) {
+        Ok(answer) => answer,
+        Err(payload) => return Row::panicked(solver, payload),
+    };
+    Row {
+        answer: Some(answer),
+        parse_ns: Some((parsed - start).as_nanos() as u64),
+        parse_stats,
+        solve_ns: Some(parsed.elapsed().as_nanos() as u64),
+        stats: aoc_common::stats::take(),
+        ..Row::new(solver, Status::Ok, None)
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    use aoc_common::parse::ParseError;
+
+    use crate::registry;
+
+    #[test]
+    fn test_map_parallel() {
+        let items = (0..100).collect::<Vec<u64>>();
+        let squares = map_parallel(&items, 8, |i| i * i);
+        assert_eq!(squares, items.iter().map(|i| i * i).collect::<Vec<_>>());
+        assert_eq!(map_parallel(&items, 0, |i| i + 1)[99], 100);
+        assert_eq!(map_parallel(&[] as &[u64], 4, |i| i + 1), Vec::<u64>::new());
+    }
+
+    fn parse_len(input: &str) -> Result<Box<dyn Any>, ParseError> {
+        if input.is_empty() {
+            return Err(ParseError::new(1, 1, "", "an input\nover two lines"));
+        }
+        aoc_common::stats::record("lines", input.lines().count());
+        Ok(Box::new(input.len()))
+    }
+
+    fn len(model: &dyn Any) -> String {
+        let len = model.downcast_ref::<usize>().unwrap();
+        aoc_common::stats::record("length", len);
+        len.to_string()
+    }
+
+    fn index(model: &dyn Any) -> String {
+        let len = model.downcast_ref::<usize>().unwrap();
+        [0][*len].to_string()
+    }
+
+    fn solver(solve: fn(&dyn Any) -> String) -> Solver {
+        Solver {
+            day: 3,
+            part: 1,
+            status: registry::Status::Implemented,
+            version: 1,
+            params: &[],
+            check_params: |_| Ok(()),
+            parse: parse_len,
+            solve,
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let four = Ok("four".to_string());
+        let row = run(&solver(len), &four);
+        assert_eq!(row.entry().unwrap().answer, "4");
+        assert_eq!(row.entry().unwrap().stats, row.stats);
+        assert_eq!(row.entry().unwrap().parse_stats, row.parse_stats);
+        assert_eq!(
+            (row.status, row.answer, row.message),
+            (Status::Ok, Some("4".to_string()), None)
+        );
+        assert!(row.parse_ns.is_some() && row.solve_ns.is_some());
+        assert_eq!(
+            serde_json::to_value(&row.stats).unwrap(),
+            serde_json::json!({"length": 4})
+        );
+        assert_eq!(
+            serde_json::to_value(&row.parse_stats).unwrap(),
+            serde_json::json!({"lines": 1})
+        );
+
+        let row = run(&solver(index), &four);
+        assert_eq!(row.entry(), None);
+        assert_eq!(row.status, Status::Panic);
+        assert_eq!(
+            row.message.unwrap(),
+            "index out of bounds: the len is 1 but the index is 4"
+        );
+
+        let row = run(&solver(len), &Ok(String::new()));
+        assert_eq!(row.status, Status::Invalid);
+        assert_eq!(row.message.unwrap(), "line 1, column 1: expected an input");
+
+        let row = run(&solver(len), &Err("no input for day 3".to_string()));
+        assert_eq!(
+            (row.status, row.message),
+            (Status::NoInput, Some("no input for day 3".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_todo() {
+        let stub = registry::find(21, 2).unwrap();
+        assert_eq!(stub.status, registry::Status::Stub);
+        let row = run(stub, &Ok("S\n".to_string()));
+        assert_eq!(row.status, Status::Todo);
+        assert_eq!(row.answer, None);
+    }
+
+    #[test]
+    fn test_cached() {
+        let entry = Entry {
+            answer: "4".to_string(),
+            parse_ns: 1,
+            solve_ns: 2,
+            parse_stats: BTreeMap::from([("lines".to_string(), serde_json::json!(1))]),
+            stats: BTreeMap::from([("length".to_string(), serde_json::json!(4))]),
+        };
+        let row = Row::cached(&solver(len), entry.clone());
+        assert_eq!(
+            (row.status, row.answer.as_deref(), row.solve_ns, row.cached),
+            (Status::Ok, Some("4"), Some(2), true)
+        );
+        assert_eq!(row.stats, entry.stats);
+        assert_eq!(row.parse_stats, entry.parse_stats);
+        // Already in the cache
+        assert_eq!(row.entry(), None);
+    }
+}
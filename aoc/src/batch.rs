@@ -0,0 +1,245 @@
+//! `aoc batch`: one solver over many inputs, everyone's puzzle input for
+//! instance, from a pool of threads. Each input is solved like a task of
+//! `aoc run --all`, its panics caught, and ends up as a row of the table.
+
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde::Serialize;
+
+use crate::{all, registry::Solver};
+
+/// One row of the table, one input: a row of `aoc run --all` and its file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Row {
+    pub file: String,
+    #[serde(flatten)]
+    pub run: all::Row,
+}
+
+/// The files of a directory, or those matching a glob pattern, sorted. Hidden
+/// files are left out of a directory.
+pub fn inputs(pattern: &str) -> Result<Vec<PathBuf>, String> {
+    let dir = Path::new(pattern);
+    let mut paths = if dir.is_dir() {
+        let entries =
+            std::fs::read_dir(dir).map_err(|e| format!("cannot read {}: {}", dir.display(), e))?;
+        let mut paths = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("cannot read {}: {}", dir.display(), e))?;
+            if !entry.file_name().to_string_lossy().starts_with('.') {
+                paths.push(entry.path());
+            }
+        }
+        paths
+    } else {
+        let paths =
+            glob::glob(pattern).map_err(|e| format!("Invalid pattern {}, {}", pattern, e))?;
+        paths
+            .collect::<Result<Vec<PathBuf>, _>>()
+            .map_err(|e| e.to_string())?
+    };
+    paths.retain(|path| path.is_file());
+    paths.sort();
+    if paths.is_empty() {
+        return Err(format!("No input file matches {}", pattern));
+    }
+    Ok(paths)
+}
+
+/// Solve every input with at most `jobs` threads, in the order of the paths.
+pub fn run(solver: &Solver, paths: &[PathBuf], jobs: usize) -> Vec<Row> {
+    all::map_parallel(paths, jobs, |path| {
+        let input = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e));
+        Row {
+            file: path.display().to_string(),
+            run: all::run(solver, &input),
+        }
+    })
+}
+
+/// Stats as a JSON object in a single cell, empty when there are none.
+fn stats_cell(stats: &BTreeMap<String, serde_json::Value>) -> String {
+    if stats.is_empty() {
+        return String::new();
+    }
+    serde_json::to_string(stats).expect("stats serialize to JSON")
+}
+
+/// The rows as CSV, with a header line and the fields of the JSON rows.
+/// Missing values are empty, CSV has a column for each of them, and the stats
+/// are JSON objects.
+pub fn write_csv(rows: &[Row], out: impl io::Write) -> csv::Result<()> {
+    let mut writer = csv::Writer::from_writer(out);
+    writer.write_record([
+        "file",
+        "day",
+        "part",
+        "status",
+        "answer",
+        "message",
+        "parse_ns",
+        "parse_stats",
+        "solve_ns",
+        "cached",
+        "stats",
+    ])?;
+    for Row { file, run } in rows {
+        writer.serialize((
+            file,
+            run.day,
+            run.part,
+            run.status,
+            &run.answer,
+            &run.message,
+            run.parse_ns,
+            stats_cell(&run.parse_stats),
+            run.solve_ns,
+            run.cached,
+            stats_cell(&run.stats),
+        ))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    use crate::{all::Status, registry};
+
+    const EXAMPLE: &str = "Time:      7  15   30\nDistance:  9  40  200\n";
+
+    #[test]
+    fn test_inputs() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["bob.txt", "alice.txt", ".hidden", "notes.md"] {
+            std::fs::write(dir.path().join(name), "").unwrap();
+        }
+        std::fs::create_dir(dir.path().join("old")).unwrap();
+
+        let names = |pattern: &str| {
+            inputs(pattern).map(|paths| {
+                paths
+                    .iter()
+                    .map(|p| p.file_name().unwrap().to_str().unwrap().to_string())
+                    .collect::<Vec<_>>()
+            })
+        };
+        let dir = dir.path().to_str().unwrap();
+        assert_eq!(
+            names(dir).unwrap(),
+            vec!["alice.txt", "bob.txt", "notes.md"]
+        );
+        assert_eq!(
+            names(&format!("{}/*.txt", dir)).unwrap(),
+            vec!["alice.txt", "bob.txt"]
+        );
+        assert_eq!(
+            names(&format!("{}/*.csv", dir)).unwrap_err(),
+            format!("No input file matches {}/*.csv", dir)
+        );
+        assert!(names("[").is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let good = dir.path().join("good.txt");
+        let bad = dir.path().join("bad.txt");
+        std::fs::write(&good, EXAMPLE).unwrap();
+        std::fs::write(&bad, "Time: 7\n").unwrap();
+        let missing = dir.path().join("missing.txt");
+
+        let rows = run(
+            registry::find(6, 1).unwrap(),
+            &[good.clone(), bad, missing],
+            2,
+        );
+        assert_eq!(
+            rows.iter().map(|r| r.run.status).collect::<Vec<_>>(),
+            vec![Status::Ok, Status::Invalid, Status::NoInput]
+        );
+        assert_eq!(rows[0].file, good.display().to_string());
+        assert_eq!(
+            (rows[0].run.answer.as_deref(), &rows[0].run.message),
+            (Some("288"), &None)
+        );
+        assert!(rows[0].run.parse_ns.is_some() && rows[0].run.solve_ns.is_some());
+        assert!(rows[1].run.message.is_some() && rows[1].run.answer.is_none());
+        assert!(rows[2]
+            .run
+            .message
+            .as_ref()
+            .unwrap()
+            .starts_with("cannot read"));
+    }
+
+    fn rows() -> [Row; 2] {
+        let row = all::Row {
+            day: 6,
+            part: 1,
+            status: Status::Ok,
+            answer: Some("288".to_string()),
+            message: None,
+            parse_ns: Some(1200),
+            parse_stats: BTreeMap::new(),
+            solve_ns: Some(3400),
+            cached: false,
+            stats: BTreeMap::from([
+                ("races".to_string(), serde_json::json!(3)),
+                ("ways".to_string(), serde_json::json!([4, 8, 9])),
+            ]),
+        };
+        [
+            Row {
+                file: "inputs/alice.txt".to_string(),
+                run: row.clone(),
+            },
+            Row {
+                file: "inputs/bob, jr.txt".to_string(),
+                run: all::Row {
+                    status: Status::NoInput,
+                    answer: None,
+                    message: Some("cannot read".to_string()),
+                    parse_ns: None,
+                    solve_ns: None,
+                    stats: BTreeMap::new(),
+                    ..row
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut csv = Vec::new();
+        write_csv(&rows(), &mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "file,day,part,status,answer,message,parse_ns,parse_stats,solve_ns,cached,stats\n\
+             inputs/alice.txt,6,1,ok,288,,1200,,3400,false,\"{\"\"races\"\":3,\"\"ways\"\":[4,8,9]}\"\n\
+             \"inputs/bob, jr.txt\",6,1,no_input,,cannot read,,,,false,\n"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        // The rows of `aoc run --all` with their file
+        let [alice, bob] = rows();
+        let mut expected = serde_json::to_value(&bob.run).unwrap();
+        expected["file"] = bob.file.clone().into();
+        assert_eq!(serde_json::to_value(&bob).unwrap(), expected);
+        assert_eq!(
+            serde_json::to_string(&alice).unwrap(),
+            r#"{"file":"inputs/alice.txt","day":6,"part":1,"status":"ok","answer":"288","parse_ns":1200,"solve_ns":3400,"stats":{"races":3,"ways":[4,8,9]}}"#
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use std::time::{Duration, Instant};
+
+use aoc_common::parse::ParseError;
+use serde::{Deserialize, Serialize};
+
+use crate::registry::Solver;
+
+/// Medians closer than this to the baseline are noise, whatever the ratio.
+pub const NOISE_FLOOR: Duration = Duration::from_micros(100);
+
+/// Min, median and max of the runs of one phase, in nanoseconds so the JSON
+/// stays plain integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    pub fn new(mut durations: Vec<Duration>) -> Self {
+        assert!(!durations.is_empty(), "no runs to compute stats from");
+        durations.sort();
+        let ns = |d: Duration| d.as_nanos() as u64;
+        let middle = durations.len() / 2;
+        let median = if durations.len().is_multiple_of(2) {
+            (durations[middle - 1] + durations[middle]) / 2
+        } else {
+            durations[middle]
+        };
+        Stats {
+            min_ns: ns(durations[0]),
+            median_ns: ns(median),
+            max_ns: ns(durations[durations.len() - 1]),
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!(
+            "{} / {} / {}",
+            format_duration(Duration::from_nanos(self.min_ns)),
+            format_duration(Duration::from_nanos(self.median_ns)),
+            format_duration(Duration::from_nanos(self.max_ns))
+        );
+        f.pad(&s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// What `aoc bench --json` writes and `--baseline` reads back.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub entries: Vec<Entry>,
+}
+
+impl Report {
+    pub fn find(&self, day: u8, part: u8) -> Option<&Entry> {
+        self.entries.iter().find(|e| e.day == day && e.part == part)
+    }
+}
+
+/// Time the parsing and the solving of `input` separately, `runs` times. The
+/// answer is dropped, `aoc verify` is there to check it.
+pub fn measure(solver: &Solver, input: &str, runs: usize) -> Result<Entry, ParseError> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut solve = Vec::with_capacity(runs);
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let model = (solver.parse)(input)?;
+        let parsed = Instant::now();
+        let answer = (solver.solve)(model.as_ref());
+        parse.push(parsed - start);
+        solve.push(parsed.elapsed());
+        log::debug!("day {} part {}: {}", solver.day, solver.part, answer);
+    }
+    Ok(Entry {
+        day: solver.day,
+        part: solver.part,
+        runs: parse.len(),
+        parse: Stats::new(parse),
+        solve: Stats::new(solve),
+    })
+}
+
+/// How a median moved compared to the baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    Faster(f64),
+    Same,
+    Slower(f64),
+}
+
+impl Change {
+    /// `threshold` is the relative change under which both are the same,
+    /// 0.1 for 10 %.
+    pub fn new(baseline: Duration, current: Duration, threshold: f64) -> Self {
+        if baseline.abs_diff(current) < NOISE_FLOOR || baseline.is_zero() {
+            return Change::Same;
+        }
+        let ratio = current.as_secs_f64() / baseline.as_secs_f64() - 1.0;
+        if ratio > threshold {
+            Change::Slower(ratio)
+        } else if ratio < -threshold {
+            Change::Faster(ratio)
+        } else {
+            Change::Same
+        }
+    }
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Faster(ratio) => write!(f, "faster ({:+.0}%)", ratio * 100.0),
+            Change::Same => write!(f, "same"),
+            Change::Slower(ratio) => write!(f, "REGRESSION ({:+.0}%)", ratio * 100.0),
+        }
+    }
+}
+
+/// Compare the parse and solve medians with the baseline.
+pub fn compare(baseline: &Entry, current: &Entry, threshold: f64) -> (Change, Change) {
+    (
+        Change::new(baseline.parse.median(), current.parse.median(), threshold),
+        Change::new(baseline.solve.median(), current.solve.median(), threshold),
+    )
+}
+
+/// Short human readable duration, three significant digits at most.
+pub fn format_duration(d: Duration) -> String {
+    let ns = d.as_nanos();
+    if ns < 1_000 {
+        format!("{}ns", ns)
+    } else if ns < 1_000_000 {
+        format!("{:.1}µs", ns as f64 / 1e3)
+    } else if ns < 1_000_000_000 {
+        format!("{:.1}ms", ns as f64 / 1e6)
+    } else {
+        format!("{:.2}s", ns as f64 / 1e9)
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(vec![ms(5), ms(1), ms(3)]);
+        assert_eq!(
+            stats,
+            Stats {
+                min_ns: 1_000_000,
+                median_ns: 3_000_000,
+                max_ns: 5_000_000
+            }
+        );
+        assert_eq!(Stats::new(vec![ms(4), ms(1), ms(2), ms(9)]).median(), ms(3));
+        assert_eq!(stats.to_string(), "1.0ms / 3.0ms / 5.0ms");
+    }
+
+    #[test]
+    fn test_change() {
+        assert_eq!(Change::new(ms(10), ms(10), 0.1), Change::Same);
+        assert_eq!(Change::new(ms(10), ms(11), 0.2), Change::Same);
+        assert_eq!(Change::new(ms(10), ms(15), 0.2), Change::Slower(0.5));
+        assert_eq!(Change::new(ms(10), ms(5), 0.2), Change::Faster(-0.5));
+        // Twice as slow, but only by a few microseconds
+        assert_eq!(
+            Change::new(Duration::from_micros(5), Duration::from_micros(10), 0.2),
+            Change::Same
+        );
+        assert_eq!(Change::Slower(0.5).to_string(), "REGRESSION (+50%)");
+    }
+
+    #[test]
+    fn test_report_round_trip() {
+        let entry = Entry {
+            day: 5,
+            part: 2,
+            runs: 3,
+            parse: Stats::new(vec![ms(1)]),
+            solve: Stats::new(vec![ms(2), ms(3)]),
+        };
+        let report = Report {
+            entries: vec![entry.clone()],
+        };
+        let json = serde_json::to_string(&report).unwrap();
+        let report: Report = serde_json::from_str(&json).unwrap();
+        assert_eq!(report.find(5, 2), Some(&entry));
+        assert_eq!(report.find(5, 1), None);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(950)), "950ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3µs");
+        assert_eq!(format_duration(ms(250)), "250.0ms");
+        assert_eq!(format_duration(ms(2_500)), "2.50s");
+    }
+}
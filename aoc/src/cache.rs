@@ -0,0 +1,217 @@
+//! Answers already found, so that running a slow solver again on the same
+//! input returns at once. An answer is keyed by the SHA-256 of the day, the
+//! part, the version of the solver, whether the arithmetic is checked, the
+//! params set for the day and the input bytes, and stored as one small JSON
+//! file.
+
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{checked, params};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::registry::Solver;
+
+/// A cached answer, with the timings and stats of the run that found it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub answer: String,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    /// What the parser recorded, missing from the entries cached before the
+    /// stats were.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub parse_stats: BTreeMap<String, serde_json::Value>,
+    /// What the solver recorded, missing from the entries cached before the
+    /// stats were.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub stats: BTreeMap<String, serde_json::Value>,
+}
+
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    /// `$AOC_CACHE`, or `aoc-2023` in the user's cache directory.
+    pub fn from_env() -> Option<Self> {
+        if let Some(dir) = env::var_os("AOC_CACHE") {
+            return Some(Cache::new(dir));
+        }
+        let base = env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))?;
+        Some(Cache::new(base.join("aoc-2023")))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, solver: &Solver, input: &str) -> PathBuf {
+        let mut hasher = Sha256::new();
+        let overrides = params::overrides(solver.day);
+        hasher.update(format!(
+            "{}/{}/{}{}{}\n",
+            solver.day,
+            solver.part,
+            solver.version,
+            // A checked build must not trust the answers that wrapped around
+            if checked::ENABLED { "/checked" } else { "" },
+            if overrides.is_empty() {
+                String::new()
+            } else {
+                format!("/{}", serde_json::json!(overrides))
+            }
+        ));
+        hasher.update(input.as_bytes());
+        let key = hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>();
+        self.dir.join(format!(
+            "day_{:02}_{}_{}.json",
+            solver.day, solver.part, key
+        ))
+    }
+
+    /// The cached answer of the solver for this input. A missing or unreadable
+    /// entry is a miss.
+    pub fn get(&self, solver: &Solver, input: &str) -> Option<Entry> {
+        let path = self.path(solver, input);
+        let content = fs::read_to_string(&path).ok()?;
+        match serde_json::from_str(&content) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                log::warn!("ignoring invalid cache entry {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
+
+    pub fn put(&self, solver: &Solver, input: &str, entry: &Entry) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.path(solver, input);
+        // Written aside then renamed, a reader never sees half an entry
+        let partial = path.with_extension(format!("{}.tmp", std::process::id()));
+        fs::write(&partial, serde_json::to_string(entry)?)?;
+        fs::rename(&partial, &path)
+    }
+
+    /// Remove the entries of a day, or all of them. Returns how many were
+    /// removed.
+    pub fn clear(&self, day: Option<u8>) -> io::Result<usize> {
+        let prefix = match day {
+            Some(day) => format!("day_{:02}_", day),
+            None => "day_".to_string(),
+        };
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e),
+        };
+        let mut removed = 0;
+        for entry in entries {
+            let path = entry?.path();
+            let name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("");
+            if name.starts_with(&prefix) && name.ends_with(".json") {
+                fs::remove_file(&path)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    use crate::registry;
+
+    fn entry(answer: &str) -> Entry {
+        Entry {
+            answer: answer.to_string(),
+            parse_ns: 10,
+            solve_ns: 20,
+            parse_stats: BTreeMap::new(),
+            stats: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_get_put() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path().join("cache"));
+        let solver = registry::find(5, 2).unwrap();
+        assert_eq!(cache.get(solver, "seeds: 1 2"), None);
+
+        cache.put(solver, "seeds: 1 2", &entry("46")).unwrap();
+        assert_eq!(cache.get(solver, "seeds: 1 2"), Some(entry("46")));
+        assert_eq!(cache.get(solver, "seeds: 1 3"), None);
+        assert_eq!(cache.get(registry::find(5, 1).unwrap(), "seeds: 1 2"), None);
+        let bumped = Solver {
+            version: solver.version + 1,
+            ..*solver
+        };
+        assert_eq!(cache.get(&bumped, "seeds: 1 2"), None);
+
+        cache.put(solver, "seeds: 1 2", &entry("47")).unwrap();
+        assert_eq!(cache.get(solver, "seeds: 1 2"), Some(entry("47")));
+        assert_eq!(fs::read_dir(cache.dir()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_stats() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path());
+        let solver = registry::find(10, 1).unwrap();
+        let with_stats = Entry {
+            parse_stats: BTreeMap::from([("width".to_string(), serde_json::json!(5))]),
+            stats: BTreeMap::from([("loop_length".to_string(), serde_json::json!(16))]),
+            ..entry("8")
+        };
+        cache.put(solver, "input", &with_stats).unwrap();
+        assert_eq!(cache.get(solver, "input"), Some(with_stats));
+
+        // Cached before the stats were
+        let path = cache.path(solver, "input");
+        fs::write(&path, r#"{"answer":"8","parse_ns":10,"solve_ns":20}"#).unwrap();
+        assert_eq!(cache.get(solver, "input"), Some(entry("8")));
+    }
+
+    #[test]
+    fn test_clear() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path());
+        assert_eq!(
+            Cache::new(dir.path().join("nothing")).clear(None).unwrap(),
+            0
+        );
+        for (day, part) in [(5, 1), (5, 2), (16, 1)] {
+            let solver = registry::find(day, part).unwrap();
+            cache.put(solver, "input", &entry("1")).unwrap();
+        }
+        fs::write(dir.path().join("notes.txt"), "kept").unwrap();
+
+        assert_eq!(cache.clear(Some(5)).unwrap(), 2);
+        assert_eq!(cache.get(registry::find(5, 1).unwrap(), "input"), None);
+        assert!(cache.get(registry::find(16, 1).unwrap(), "input").is_some());
+        assert_eq!(cache.clear(None).unwrap(), 1);
+        assert!(dir.path().join("notes.txt").exists());
+    }
+}
@@ -0,0 +1,1046 @@
+use std::{
+    collections::BTreeMap,
+    net::SocketAddr,
+    panic,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{
+    answers::{self, Answers},
+    fixtures,
+    input::Source,
+    params,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+mod all;
+mod batch;
+mod bench;
+mod cache;
+mod registry;
+mod serve;
+mod view;
+
+use bench::{Change, Report};
+use cache::Cache;
+use registry::{Solver, Status, DAYS, PARTS};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run the advent of code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log more from the solvers: -v info, -vv debug, -vvv trace [default: AOC_LOG or errors only]
+    #[arg(long, short, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    /// File of the params of the days [default: AOC_CONFIG or aoc.toml]
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+    /// Set a param of the days declaring it, `day_XX.name=value` for a
+    /// single day
+    #[arg(long = "param", value_name = "NAME=VALUE", global = true)]
+    params: Vec<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solvers of a day, both parts from a single parse unless one is
+    /// given
+    Run {
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        part: Option<u8>,
+        /// Puzzle input file, `-` to read it from stdin [default: day_XX/input.txt]
+        #[arg(long, short)]
+        input: Option<PathBuf>,
+        /// Run on the example N of day_XX/fixtures and check its answers
+        #[arg(long, short, conflicts_with = "input")]
+        example: Option<u8>,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Run every day and part on its default input, in parallel, and print
+        /// a table of the answers
+        #[arg(long, conflicts_with_all = ["day", "part", "input", "example"])]
+        all: bool,
+        /// Days and parts run at once with --all [default: the number of CPUs]
+        #[arg(long, short, requires = "all")]
+        jobs: Option<usize>,
+        /// Solve even when the answer is cached, and cache the new one
+        #[arg(long)]
+        no_cache: bool,
+    },
+    /// Run the solver of a part on many inputs, in parallel, and print a
+    /// table of the answers
+    Batch {
+        day: u8,
+        part: u8,
+        /// A directory of input files, or a glob pattern like 'inputs/*.txt'
+        inputs: String,
+        /// Inputs solved at once [default: the number of CPUs]
+        #[arg(long, short)]
+        jobs: Option<usize>,
+        /// How to print the table
+        #[arg(long, value_enum, default_value_t = TableFormat::Text)]
+        format: TableFormat,
+    },
+    /// Step through the simulation of a day in the terminal (days 14, 16,
+    /// 20 and 21)
+    View {
+        day: u8,
+        /// Puzzle input file, `-` to read it from stdin [default: day_XX/input.txt]
+        #[arg(long, short)]
+        input: Option<PathBuf>,
+        /// View the example N of day_XX/fixtures
+        #[arg(long, short, conflicts_with = "input")]
+        example: Option<u8>,
+    },
+    /// Answer `POST /solve` requests with the answers as JSON, see the README
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:8023")]
+        listen: SocketAddr,
+        /// Seconds a request waits for the solvers before giving up
+        #[arg(long, default_value_t = 10)]
+        timeout: u64,
+        /// Requests solved at once, past that they are refused
+        #[arg(long, default_value_t = 4)]
+        workers: usize,
+        /// Requests answered at once, solved or waiting, past that they are
+        /// refused
+        #[arg(long, default_value_t = 64)]
+        connections: usize,
+    },
+    /// Solve one request of `serve`, read from stdin
+    #[command(hide = true)]
+    ServeWorker,
+    /// Manage the answers cached by `run`
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+    /// Show which days and parts are implemented, stubbed or missing
+    List,
+    /// Print the params of the days with their current values, as a config
+    /// file
+    Params,
+    /// Run every implemented solver with a known answer on its default input
+    /// and check that it still finds it
+    Verify {
+        /// Only verify this day
+        day: Option<u8>,
+        /// Known answers file [default: answers.txt]
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Time parsing and solving separately on the default inputs
+    Bench {
+        /// Days to benchmark [default: every solver with a known answer]
+        days: Vec<u8>,
+        /// Number of runs of each solver
+        #[arg(long, short, default_value_t = 10)]
+        runs: usize,
+        /// Write the results as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// Compare with the results of an earlier `--json` run
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Relative slowdown of a median reported as a regression
+        #[arg(long, default_value_t = 0.2)]
+        threshold: f64,
+    },
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// Forget the cached answers, of every day unless one is given
+    Clear { day: Option<u8> },
+    /// Print the directory of the cache
+    Path,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// `Part N: answer` lines
+    Text,
+    /// One JSON object with the answers, timings and the solvers' stats
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum TableFormat {
+    /// Aligned columns and a summary line
+    Text,
+    /// Comma-separated values with a header line
+    Csv,
+    /// A JSON array of the rows
+    Json,
+}
+
+/// What `aoc run --format json` prints.
+#[derive(Debug, Serialize)]
+struct RunReport {
+    day: u8,
+    input: String,
+    /// SHA-256 of the input, to tell which input the answers are for.
+    input_sha256: String,
+    parse_ns: u64,
+    /// What the parser recorded with `aoc_common::stats`, apart from the
+    /// stats of the parts sharing its model.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    parse_stats: BTreeMap<String, serde_json::Value>,
+    parts: Vec<PartReport>,
+}
+
+#[derive(Debug, Serialize)]
+struct PartReport {
+    part: u8,
+    answer: String,
+    solve_ns: u64,
+    /// The answer of the example, when running one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<String>,
+    /// The answer and its time come from the cache.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    cached: bool,
+    /// What the solver recorded with `aoc_common::stats`, cached along with
+    /// the answer.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    stats: BTreeMap<String, serde_json::Value>,
+}
+
+fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// The input of an example fixture and its expected answers.
+fn example_source(day: u8, number: u8) -> Result<(Source, Answers), String> {
+    let fixtures = fixtures::load(day)?;
+    let fixture = fixtures.get(number).ok_or_else(|| {
+        format!(
+            "No example {} for day {} in day_{:02}/fixtures",
+            number, day, day
+        )
+    })?;
+    Ok((Source::File(fixture.path.clone()), fixtures.answers))
+}
+
+fn run(
+    day: u8,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    example: Option<u8>,
+    format: Format,
+    no_cache: bool,
+) -> ExitCode {
+    let solvers = match part {
+        Some(part) => registry::find(day, part).into_iter().collect::<Vec<_>>(),
+        None => registry::find_day(day).collect(),
+    };
+    let Some(first) = solvers.first() else {
+        match part {
+            Some(part) => eprintln!("No solver for day {} part {}", day, part),
+            None => eprintln!("No solver for day {}", day),
+        }
+        return ExitCode::FAILURE;
+    };
+    if solvers.iter().all(|s| s.status != Status::Implemented) {
+        match part {
+            Some(part) => eprintln!("Day {} part {} is not implemented yet", day, part),
+            None => eprintln!("Day {} is not implemented yet", day),
+        }
+        return ExitCode::FAILURE;
+    }
+
+    let (source, expected) = match example {
+        None => (Source::new(day, input), Answers::default()),
+        Some(number) => match example_source(day, number) {
+            Ok(example) => example,
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+    log::info!("Reading the input of day {} from {}", day, source);
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let cache = Cache::from_env();
+    // Both parts of a day share the model, one parse is enough, and none at
+    // all when both answers are cached
+    aoc_common::stats::take();
+    let mut model = None;
+    let mut report = RunReport {
+        day,
+        input: source.to_string(),
+        input_sha256: input_hash(&input),
+        parse_ns: 0,
+        parse_stats: BTreeMap::new(),
+        parts: Vec::new(),
+    };
+    let mut wrong = 0;
+    for &solver in &solvers {
+        if solver.status != Status::Implemented {
+            eprintln!("Day {} part {} is not implemented yet", day, solver.part);
+            continue;
+        }
+        let cached = cache
+            .as_ref()
+            .filter(|_| !no_cache)
+            .and_then(|cache| cache.get(solver, &input));
+        let is_cached = cached.is_some();
+        let entry = match cached {
+            Some(entry) => {
+                log::info!("Part {} answer from the cache", solver.part);
+                entry
+            }
+            None => {
+                if model.is_none() {
+                    let start = Instant::now();
+                    match (first.parse)(&input) {
+                        Ok(parsed) => {
+                            let parse_ns = start.elapsed().as_nanos() as u64;
+                            model = Some((parsed, parse_ns, aoc_common::stats::take()))
+                        }
+                        Err(e) => {
+                            eprintln!("Invalid puzzle input in {}, {}", source, e);
+                            return ExitCode::FAILURE;
+                        }
+                    }
+                }
+                let (model, parse_ns, parse_stats) = model.as_ref().unwrap();
+                let start = Instant::now();
+                let entry = cache::Entry {
+                    answer: (solver.solve)(model.as_ref()),
+                    parse_ns: *parse_ns,
+                    solve_ns: start.elapsed().as_nanos() as u64,
+                    parse_stats: parse_stats.clone(),
+                    stats: aoc_common::stats::take(),
+                };
+                if let Some(cache) = &cache {
+                    if let Err(e) = cache.put(solver, &input, &entry) {
+                        log::warn!("cannot cache the answer of part {}: {}", solver.part, e);
+                    }
+                }
+                entry
+            }
+        };
+        let cache::Entry {
+            answer,
+            parse_ns,
+            solve_ns,
+            parse_stats,
+            stats,
+        } = entry;
+        if report.parts.is_empty() || !is_cached {
+            report.parse_ns = parse_ns;
+            report.parse_stats = parse_stats;
+        }
+        if format == Format::Text {
+            println!("Part {}: {}", solver.part, answer);
+        }
+        let expected = example.and_then(|number| expected.get(number, solver.part));
+        if let Some(expected) = expected.filter(|&expected| expected != answer) {
+            eprintln!(
+                "Part {}: expected {} for this example",
+                solver.part, expected
+            );
+            wrong += 1;
+        }
+        report.parts.push(PartReport {
+            part: solver.part,
+            answer,
+            solve_ns,
+            expected: expected.map(str::to_string),
+            cached: is_cached,
+            stats,
+        });
+    }
+    if format == Format::Json {
+        match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("cannot write the answers as JSON: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if wrong == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn run_all(jobs: Option<usize>, format: Format, no_cache: bool) -> ExitCode {
+    let jobs = jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, usize::from));
+    let solvers = registry::SOLVERS
+        .iter()
+        .filter(|s| s.status != Status::Missing)
+        .collect::<Vec<_>>();
+    // The panics are in the table, not all over it
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| log::debug!("{}", info)));
+    let cache = Cache::from_env();
+    let rows = all::map_parallel(&solvers, jobs, |solver| {
+        let input = Source::new(solver.day, None)
+            .read()
+            .map_err(|e| e.to_string());
+        let cached = cache
+            .as_ref()
+            .filter(|_| !no_cache)
+            .zip(input.as_ref().ok())
+            .and_then(|(cache, input)| cache.get(solver, input));
+        if let Some(entry) = cached {
+            return all::Row::cached(solver, entry);
+        }
+        let row = all::run(solver, &input);
+        if let (Some(cache), Some(entry), Ok(input)) = (&cache, row.entry(), &input) {
+            if let Err(e) = cache.put(solver, input, &entry) {
+                log::warn!("cannot cache the answer of day {}: {}", solver.day, e);
+            }
+        }
+        row
+    });
+    panic::set_hook(hook);
+
+    if format == Format::Json {
+        match serde_json::to_string_pretty(&rows) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("cannot write the answers as JSON: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        println!("Day  Part  Status    Parse     Solve     Answer");
+        for row in &rows {
+            let duration = |ns: Option<u64>| {
+                ns.map_or("-".to_string(), |ns| {
+                    bench::format_duration(Duration::from_nanos(ns))
+                })
+            };
+            let answer = match (&row.answer, &row.message) {
+                (Some(answer), _) => answer.as_str(),
+                (None, Some(message)) => message.as_str(),
+                (None, None) => "-",
+            };
+            let status = if row.cached {
+                "cached".to_string()
+            } else {
+                row.status.to_string()
+            };
+            println!(
+                "{:>3}  {:>4}  {:<8}  {:<8}  {:<8}  {}",
+                row.day,
+                row.part,
+                status,
+                duration(row.parse_ns),
+                duration(row.solve_ns),
+                answer
+            );
+        }
+        let count = |status| rows.iter().filter(|r| r.status == status).count();
+        println!(
+            "{} ok, {} panicked, {} todo, {} without input, {} invalid",
+            count(all::Status::Ok),
+            count(all::Status::Panic),
+            count(all::Status::Todo),
+            count(all::Status::NoInput),
+            count(all::Status::Invalid)
+        );
+    }
+
+    // Stubs and missing inputs are expected, a broken solver is not
+    let broken = rows
+        .iter()
+        .any(|r| matches!(r.status, all::Status::Panic | all::Status::Invalid));
+    if broken {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn view(day: u8, input: Option<PathBuf>, example: Option<u8>) -> ExitCode {
+    let Some(simulator) = registry::find_simulator(day) else {
+        let days = registry::SIMULATORS
+            .iter()
+            .map(|s| s.day.to_string())
+            .collect::<Vec<_>>();
+        eprintln!(
+            "Day {} has no simulation to view, try day {}",
+            day,
+            days.join(", ")
+        );
+        return ExitCode::FAILURE;
+    };
+    let source = match example {
+        None => Source::new(day, input),
+        Some(number) => match example_source(day, number) {
+            Ok((source, _)) => source,
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let simulation = match (simulator.start)(&input) {
+        Ok(simulation) => simulation,
+        Err(e) => {
+            eprintln!("Invalid puzzle input in {}, {}", source, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    match view::run(day, simulation) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Terminal error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn serve(listen: SocketAddr, timeout: u64, workers: usize, connections: usize) -> ExitCode {
+    let config = serve::Config {
+        timeout: Duration::from_secs(timeout),
+        workers,
+        connections,
+        worker: serve::worker,
+    };
+    let server = match serve::Server::bind(listen, registry::SOLVERS, config) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!("cannot listen on {}: {}", listen, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    eprintln!("Listening on http://{}/solve", server.local_addr());
+    server.run();
+    ExitCode::SUCCESS
+}
+
+fn batch(day: u8, part: u8, pattern: &str, jobs: Option<usize>, format: TableFormat) -> ExitCode {
+    let Some(solver) = registry::find(day, part) else {
+        eprintln!("No solver for day {} part {}", day, part);
+        return ExitCode::FAILURE;
+    };
+    if solver.status != Status::Implemented {
+        eprintln!("Day {} part {} is not implemented yet", day, part);
+        return ExitCode::FAILURE;
+    }
+    let paths = match batch::inputs(pattern) {
+        Ok(paths) => paths,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let jobs = jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, usize::from));
+    // The panics are in the table, not all over it
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| log::debug!("{}", info)));
+    let rows = batch::run(solver, &paths, jobs);
+    panic::set_hook(hook);
+
+    match format {
+        TableFormat::Text => {
+            let width = rows.iter().map(|r| r.file.len()).max().unwrap_or(0);
+            println!("{:<width$}  Status    Parse     Solve     Answer", "File");
+            for batch::Row { file, run } in &rows {
+                let duration = |ns: Option<u64>| {
+                    ns.map_or("-".to_string(), |ns| {
+                        bench::format_duration(Duration::from_nanos(ns))
+                    })
+                };
+                println!(
+                    "{:<width$}  {:<8}  {:<8}  {:<8}  {}",
+                    file,
+                    run.status,
+                    duration(run.parse_ns),
+                    duration(run.solve_ns),
+                    run.answer
+                        .as_ref()
+                        .or(run.message.as_ref())
+                        .map_or("-", |s| s)
+                );
+            }
+            let ok = rows
+                .iter()
+                .filter(|r| r.run.status == all::Status::Ok)
+                .count();
+            println!("{} ok, {} failed", ok, rows.len() - ok);
+        }
+        TableFormat::Csv => {
+            if let Err(e) = batch::write_csv(&rows, std::io::stdout().lock()) {
+                eprintln!("cannot write the answers as CSV: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        TableFormat::Json => match serde_json::to_string_pretty(&rows) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("cannot write the answers as JSON: {}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+
+    if rows.iter().all(|r| r.run.status == all::Status::Ok) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn cache(command: CacheCommand) -> ExitCode {
+    let Some(cache) = Cache::from_env() else {
+        eprintln!("No cache directory, set AOC_CACHE or HOME");
+        return ExitCode::FAILURE;
+    };
+    match command {
+        CacheCommand::Clear { day } => match cache.clear(day) {
+            Ok(removed) => {
+                println!("Removed {} cached answers", removed);
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("cannot clear {}: {}", cache.dir().display(), e);
+                ExitCode::FAILURE
+            }
+        },
+        CacheCommand::Path => {
+            println!("{}", cache.dir().display());
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+fn list() -> ExitCode {
+    println!("Day  Part 1       Part 2");
+    for day in DAYS {
+        let status = PARTS
+            .map(|part| format!("{:<12}", registry::status(day, part)))
+            .collect::<Vec<String>>()
+            .join(" ");
+        println!("{:>3}  {}", day, status.trim_end());
+    }
+    ExitCode::SUCCESS
+}
+
+fn print_params() -> ExitCode {
+    for day in DAYS.filter(|day| !registry::params(*day).is_empty()) {
+        let overrides = params::overrides(day);
+        println!("[day_{:02}]", day);
+        for param in registry::params(day) {
+            println!("# {}", param.doc);
+            match overrides.get(param.name) {
+                Some(value) => println!("{} = {} # default {}", param.name, value, param.default),
+                None => println!("{} = {}", param.name, param.default),
+            }
+        }
+        println!();
+    }
+    ExitCode::SUCCESS
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Pass,
+    Fail,
+    /// No known answer to compare with.
+    Missing,
+}
+
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Outcome::Pass => "pass",
+            Outcome::Fail => "fail",
+            Outcome::Missing => "missing",
+        };
+        f.pad(s)
+    }
+}
+
+fn outcome(expected: &str, answer: &Result<String, String>) -> Outcome {
+    match answer {
+        Ok(answer) if answer == expected => Outcome::Pass,
+        _ => Outcome::Fail,
+    }
+}
+
+fn solve(solver: &Solver) -> Result<String, String> {
+    let input = Source::new(solver.day, None)
+        .read()
+        .map_err(|e| e.to_string())?;
+    // Only the first line of a parse error, the table has one row per part
+    solver
+        .run(&input)
+        .map_err(|e| e.to_string().lines().next().unwrap_or("").to_string())
+}
+
+fn load_answers(path: Option<PathBuf>) -> Result<Answers, String> {
+    let Some(path) = path.or_else(answers::default_path) else {
+        return Err(format!(
+            "cannot find {} in the current directory or its parents (use --answers <path>)",
+            answers::FILE_NAME
+        ));
+    };
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("cannot read answers from {}: {}", path.display(), e))?;
+    Answers::parse(&content).map_err(|e| format!("Invalid answers in {}, {}", path.display(), e))
+}
+
+fn verify(day: Option<u8>, answers: Option<PathBuf>) -> ExitCode {
+    let answers = match load_answers(answers) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut counts = [0; 3];
+    println!("Day  Part  Result   Expected         Answer");
+    for solver in registry::SOLVERS
+        .iter()
+        .filter(|s| s.status == Status::Implemented)
+        .filter(|s| day.is_none_or(|d| d == s.day))
+    {
+        // Without a known answer there is nothing to compare with, and some
+        // of the older solvers take minutes, so they are not even run
+        let (outcome, answer) = match answers.get(solver.day, solver.part) {
+            Some(expected) => {
+                let answer = solve(solver);
+                let outcome = outcome(expected, &answer);
+                match answer {
+                    Ok(answer) => (outcome, answer),
+                    Err(e) => (outcome, format!("error: {}", e)),
+                }
+            }
+            None => (Outcome::Missing, "-".to_string()),
+        };
+        counts[outcome as usize] += 1;
+        println!(
+            "{:>3}  {:>4}  {:<7}  {:<15}  {}",
+            solver.day,
+            solver.part,
+            outcome,
+            answers.get(solver.day, solver.part).unwrap_or("-"),
+            answer
+        );
+    }
+    println!(
+        "{} passed, {} failed, {} missing",
+        counts[Outcome::Pass as usize],
+        counts[Outcome::Fail as usize],
+        counts[Outcome::Missing as usize]
+    );
+
+    if counts[Outcome::Fail as usize] == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn load_report(path: &PathBuf) -> Result<Report, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("cannot read baseline from {}: {}", path.display(), e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Invalid baseline in {}: {}", path.display(), e))
+}
+
+fn bench(
+    days: Vec<u8>,
+    runs: usize,
+    json: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+) -> ExitCode {
+    // Some solvers take minutes, only those known to be right and reasonably
+    // fast (they have an answer) are run unless days are given
+    let answers = if days.is_empty() {
+        match load_answers(None) {
+            Ok(answers) => Some(answers),
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        None
+    };
+    let baseline = match baseline.as_ref().map(load_report).transpose() {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut report = Report::default();
+    let mut regressions = 0;
+    println!(
+        "Day  Part  {:<32}  {:<32}  Baseline",
+        "Parse (min / median / max)", "Solve (min / median / max)"
+    );
+    for solver in registry::SOLVERS
+        .iter()
+        .filter(|s| s.status == Status::Implemented)
+        .filter(|s| match &answers {
+            Some(answers) => answers.get(s.day, s.part).is_some(),
+            None => days.contains(&s.day),
+        })
+    {
+        let input = match Source::new(solver.day, None).read() {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+        let entry = match bench::measure(solver, &input, runs) {
+            Ok(entry) => entry,
+            Err(e) => {
+                eprintln!("Invalid puzzle input of day {}, {}", solver.day, e);
+                return ExitCode::FAILURE;
+            }
+        };
+        let comparison = match baseline.as_ref().map(|b| b.find(entry.day, entry.part)) {
+            None => String::new(),
+            Some(None) => "-".to_string(),
+            Some(Some(old)) => {
+                let (parse, solve) = bench::compare(old, &entry, threshold);
+                if matches!(parse, Change::Slower(_)) || matches!(solve, Change::Slower(_)) {
+                    regressions += 1;
+                }
+                format!("parse {}, solve {}", parse, solve)
+            }
+        };
+        let row = format!(
+            "{:>3}  {:>4}  {:<32}  {:<32}  {}",
+            entry.day, entry.part, entry.parse, entry.solve, comparison
+        );
+        println!("{}", row.trim_end());
+        report.entries.push(entry);
+    }
+
+    if let Some(path) = json {
+        let written = serde_json::to_string_pretty(&report)
+            .map_err(|e| e.to_string())
+            .and_then(|json| std::fs::write(&path, json + "\n").map_err(|e| e.to_string()));
+        if let Err(e) = written {
+            eprintln!("cannot write results to {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    }
+    if baseline.is_some() {
+        println!("{} regressions", regressions);
+    }
+
+    if regressions == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// The params of the config file, then those of the command line, checked
+/// against the ones the days declare.
+fn load_params(path: Option<&Path>, overrides: &[String]) -> Result<params::Config, String> {
+    let mut config = params::Config::load(path)?;
+    for param in overrides {
+        let (name, value) = param
+            .split_once('=')
+            .ok_or_else(|| format!("Expected --param name=value, not {}", param))?;
+        let (day, name) = match name.split_once('.') {
+            Some((day, name)) => {
+                let day = day
+                    .strip_prefix("day_")
+                    .and_then(|day| day.parse::<u8>().ok())
+                    .ok_or_else(|| format!("Expected day_XX.{}, not {}", name, param))?;
+                (Some(day), name)
+            }
+            None => (None, name),
+        };
+        let mut days = DAYS
+            .filter(|d| day.is_none_or(|day| day == *d))
+            .filter(|d| registry::params(*d).iter().any(|p| p.name == name))
+            .peekable();
+        if days.peek().is_none() {
+            return Err(match day {
+                Some(day) => format!("Day {} has no param {}", day, name),
+                None => format!("No day has a param {}", name),
+            });
+        }
+        for day in days {
+            config.set(day, name, value)?;
+        }
+    }
+    for day in config.days().collect::<Vec<_>>() {
+        registry::check_params(&config, day)?;
+    }
+    Ok(config)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    aoc_common::logging::init(cli.verbose);
+    match load_params(cli.config.as_deref(), &cli.params) {
+        Ok(config) => params::set(config),
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    match cli.command {
+        Command::Run {
+            all: true,
+            jobs,
+            format,
+            no_cache,
+            ..
+        } => run_all(jobs, format, no_cache),
+        Command::Run {
+            day,
+            part,
+            input,
+            example,
+            format,
+            no_cache,
+            ..
+        } => run(
+            day.expect("clap requires a day without --all"),
+            part,
+            input,
+            example,
+            format,
+            no_cache,
+        ),
+        Command::Batch {
+            day,
+            part,
+            inputs,
+            jobs,
+            format,
+        } => batch(day, part, &inputs, jobs, format),
+        Command::Cache { command } => cache(command),
+        Command::View {
+            day,
+            input,
+            example,
+        } => view(day, input, example),
+        Command::Serve {
+            listen,
+            timeout,
+            workers,
+            connections,
+        } => serve(listen, timeout, workers, connections),
+        Command::ServeWorker => serve::work(registry::SOLVERS),
+        Command::List => list(),
+        Command::Params => print_params(),
+        Command::Verify { day, answers } => verify(day, answers),
+        Command::Bench {
+            days,
+            runs,
+            json,
+            baseline,
+            threshold,
+        } => bench(days, runs, json, baseline, threshold),
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_outcome() {
+        assert_eq!(outcome("142", &Ok("142".to_string())), Outcome::Pass);
+        assert_eq!(outcome("142", &Ok("143".to_string())), Outcome::Fail);
+        assert_eq!(outcome("142", &Err("bad input".to_string())), Outcome::Fail);
+    }
+
+    #[test]
+    fn test_load_params() {
+        let param = |param: &str| load_params(None, &[param.to_string()]);
+        let config = param("expansion=100").unwrap();
+        assert_eq!(config.day(11).to_string(), "expansion = 100\n");
+        assert_eq!(config.days().collect::<Vec<_>>(), vec![11]);
+        let config = param("day_21.steps=6").unwrap();
+        assert_eq!(config.day(21).to_string(), "steps = 6\n");
+        assert_eq!(
+            param("feeders=[\"a\", \"b\"]").unwrap().day(20).to_string(),
+            "feeders = [\"a\", \"b\"]\n"
+        );
+
+        assert_eq!(
+            param("expension=100").unwrap_err(),
+            "No day has a param expension"
+        );
+        assert_eq!(
+            param("day_1.steps=6").unwrap_err(),
+            "Day 1 has no param steps"
+        );
+        assert_eq!(
+            param("steps").unwrap_err(),
+            "Expected --param name=value, not steps"
+        );
+        assert!(param("steps=many").is_err());
+        assert!(param("21.steps=6").is_err());
+
+        // Values the solvers cannot run with
+        assert_eq!(
+            param("expansion=0").unwrap_err(),
+            "The param expansion of day 11 must be an integer from 1, not 0"
+        );
+        assert!(param("expansion=-1").is_err());
+        assert_eq!(
+            param("steps=-3").unwrap_err(),
+            "The param steps of day 21 must be an integer from 0, not -3"
+        );
+        assert!(param("max_moves=0").is_err());
+        assert_eq!(
+            param("min_rating=5000").unwrap_err(),
+            "The param min_rating of day 19 must not be above max_rating, 5000 > 4000"
+        );
+        assert!(param("feeders=[\"sh\", \"sh\"]").is_err());
+        assert!(param("feeders=[]").is_ok());
+        assert!(load_params(None, &["min_rating=5000".into(), "max_rating=6000".into()]).is_ok());
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(
+            input_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
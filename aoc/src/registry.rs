@@ -0,0 +1,234 @@
+use std::any::Any;
+
+use aoc_common::{
+    params::{Config, Param, Values},
+    parse::ParseError,
+    simulation::{Simulate, Simulation},
+    Solution,
+};
+use day_01::Day01;
+use day_02::Day02;
+use day_03::Day03;
+use day_04::Day04;
+use day_05::Day05;
+use day_06::Day06;
+use day_07::Day07;
+use day_08::Day08;
+use day_09::Day09;
+use day_10::Day10;
+use day_11::Day11;
+use day_13::Day13;
+use day_14::Day14;
+use day_15::Day15;
+use day_16::Day16;
+use day_17::Day17;
+use day_18::Day18;
+use day_19::Day19;
+use day_20::Day20;
+use day_21::Day21;
+
+/// How far the solution of a puzzle part has gone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Implemented,
+    /// The part is declared but ends on `todo!()`.
+    Stub,
+    /// No solver for this part yet.
+    Missing,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Status::Implemented => "implemented",
+            Status::Stub => "stub",
+            Status::Missing => "missing",
+        };
+        f.pad(s)
+    }
+}
+
+pub struct Solver {
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    /// `Solution::VERSION` of the day.
+    pub version: u32,
+    /// `Solution::PARAMS` of the day.
+    pub params: &'static [Param],
+    /// `Solution::check_params` of the day.
+    pub check_params: fn(&Values) -> Result<(), String>,
+    /// Parse the input into the model of the day, boxed so that every day fits
+    /// in the same table. Both parts of a day share it.
+    pub parse: fn(&str) -> Result<Box<dyn Any>, ParseError>,
+    /// Solve a model returned by `parse`.
+    pub solve: fn(&dyn Any) -> String,
+}
+
+impl Solver {
+    pub fn run(&self, input: &str) -> Result<String, ParseError> {
+        (self.parse)(input).map(|model| (self.solve)(model.as_ref()))
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<Box<dyn Any>, ParseError>
+where
+    S::Model: 'static,
+{
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn part1<S: Solution>(model: &dyn Any) -> String
+where
+    S::Model: 'static,
+{
+    S::part1(model.downcast_ref().unwrap()).to_string()
+}
+
+fn part2<S: Solution>(model: &dyn Any) -> String
+where
+    S::Model: 'static,
+{
+    S::part2(model.downcast_ref().unwrap()).to_string()
+}
+
+const fn solver<S: Solution>(part: u8, status: Status) -> Solver
+where
+    S::Model: 'static,
+{
+    Solver {
+        day: S::DAY,
+        part,
+        status,
+        version: S::VERSION,
+        params: S::PARAMS,
+        check_params: S::check_params,
+        parse: parse::<S>,
+        solve: if part == 1 { part1::<S> } else { part2::<S> },
+    }
+}
+
+pub const DAYS: std::ops::RangeInclusive<u8> = 1..=25;
+pub const PARTS: std::ops::RangeInclusive<u8> = 1..=2;
+
+pub const SOLVERS: &[Solver] = &[
+    solver::<Day01>(1, Status::Implemented),
+    solver::<Day01>(2, Status::Implemented),
+    solver::<Day02>(1, Status::Implemented),
+    solver::<Day02>(2, Status::Implemented),
+    solver::<Day03>(1, Status::Implemented),
+    solver::<Day03>(2, Status::Implemented),
+    solver::<Day04>(1, Status::Implemented),
+    solver::<Day04>(2, Status::Implemented),
+    solver::<Day05>(1, Status::Implemented),
+    solver::<Day05>(2, Status::Implemented),
+    solver::<Day06>(1, Status::Implemented),
+    solver::<Day06>(2, Status::Implemented),
+    solver::<Day07>(1, Status::Implemented),
+    solver::<Day07>(2, Status::Implemented),
+    solver::<Day08>(1, Status::Implemented),
+    solver::<Day08>(2, Status::Implemented),
+    solver::<Day09>(1, Status::Implemented),
+    solver::<Day09>(2, Status::Implemented),
+    solver::<Day10>(1, Status::Implemented),
+    solver::<Day10>(2, Status::Implemented),
+    solver::<Day11>(1, Status::Implemented),
+    solver::<Day11>(2, Status::Implemented),
+    solver::<Day13>(1, Status::Implemented),
+    solver::<Day13>(2, Status::Implemented),
+    solver::<Day14>(1, Status::Implemented),
+    solver::<Day14>(2, Status::Implemented),
+    solver::<Day15>(1, Status::Implemented),
+    solver::<Day15>(2, Status::Implemented),
+    solver::<Day16>(1, Status::Implemented),
+    solver::<Day16>(2, Status::Implemented),
+    solver::<Day17>(1, Status::Implemented),
+    solver::<Day17>(2, Status::Implemented),
+    solver::<Day18>(1, Status::Implemented),
+    solver::<Day18>(2, Status::Implemented),
+    solver::<Day19>(1, Status::Implemented),
+    solver::<Day19>(2, Status::Implemented),
+    solver::<Day20>(1, Status::Implemented),
+    solver::<Day20>(2, Status::Implemented),
+    solver::<Day21>(1, Status::Implemented),
+    solver::<Day21>(2, Status::Stub),
+];
+
+/// A day whose puzzle `aoc view` can step through.
+pub struct Simulator {
+    pub day: u8,
+    /// Parse the input and set the simulation up at its first tick.
+    pub start: fn(&str) -> Result<Box<dyn Simulation>, ParseError>,
+}
+
+fn start<S: Simulate>(input: &str) -> Result<Box<dyn Simulation>, ParseError> {
+    Ok(S::simulation(&S::parse(input)?))
+}
+
+const fn simulator<S: Simulate>() -> Simulator {
+    Simulator {
+        day: S::DAY,
+        start: start::<S>,
+    }
+}
+
+pub const SIMULATORS: &[Simulator] = &[
+    simulator::<Day14>(),
+    simulator::<Day16>(),
+    simulator::<Day20>(),
+    simulator::<Day21>(),
+];
+
+pub fn find(day: u8, part: u8) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|s| s.day == day && s.part == part)
+}
+
+/// The solvers of both parts of a day, in order.
+pub fn find_day(day: u8) -> impl Iterator<Item = &'static Solver> {
+    SOLVERS.iter().filter(move |s| s.day == day)
+}
+
+/// The params of a day, none for a day without solvers.
+pub fn params(day: u8) -> &'static [Param] {
+    find_day(day).next().map_or(&[], |s| s.params)
+}
+
+/// Check the values `config` sets for a day, see [`Config::check`].
+pub fn check_params(config: &Config, day: u8) -> Result<(), String> {
+    match find_day(day).next() {
+        Some(solver) => config.check(day, solver.params, solver.check_params),
+        None => config.check(day, &[], |_| Ok(())),
+    }
+}
+
+pub fn find_simulator(day: u8) -> Option<&'static Simulator> {
+    SIMULATORS.iter().find(|s| s.day == day)
+}
+
+pub fn status(day: u8, part: u8) -> Status {
+    find(day, part).map_or(Status::Missing, |s| s.status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_status() {
+        assert_eq!(status(1, 1), Status::Implemented);
+        assert_eq!(status(21, 2), Status::Stub);
+        assert_eq!(status(12, 1), Status::Missing);
+        assert_eq!(status(25, 2), Status::Missing);
+    }
+
+    #[test]
+    fn test_solvers_are_unique_and_in_range() {
+        for (i, s) in SOLVERS.iter().enumerate() {
+            assert!(DAYS.contains(&s.day) && PARTS.contains(&s.part));
+            assert!(SOLVERS[i + 1..]
+                .iter()
+                .all(|o| (o.day, o.part) != (s.day, s.part)));
+        }
+    }
+}
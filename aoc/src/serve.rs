@@ -0,0 +1,651 @@
+//! `aoc serve`: answer `POST /solve` requests on a local port, for tools that
+//! want to run the solvers on their own inputs.
+//!
+//! The request is a JSON object with the day, the part (both parts when left
+//! out) and the input:
+//!
+//! ```json
+//! {"day": 2, "part": 1, "input": "Game 1: 3 blue, 4 red\n"}
+//! ```
+//!
+//! The answer is the same object as `aoc run --format json` without the input
+//! name, errors are `{"error": "..."}` with a 4xx or 5xx status.
+//!
+//! Each request is solved by a worker process, `aoc serve-worker`, killed
+//! when it takes too long: a solver stuck on an input cannot be stopped from
+//! another thread, but its process can.
+
+use std::{
+    collections::BTreeMap,
+    env,
+    io::{self, Read, Write},
+    net::SocketAddr,
+    path::PathBuf,
+    process::{self, ExitCode, Stdio},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_common::params;
+use serde::{Deserialize, Serialize};
+use tiny_http::{Header, Method, Request, Response};
+
+use crate::{
+    input_hash,
+    registry::{Solver, Status},
+    PartReport,
+};
+
+/// Inputs are a few dozen KiB, anything much bigger is a mistake.
+pub const MAX_BODY: usize = 1 << 20;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    /// How long a request waits for the solvers before its worker is killed.
+    pub timeout: Duration,
+    /// Requests being solved at once, past that they are refused.
+    pub workers: usize,
+    /// Requests being answered at once, waiting for a worker or not. Past
+    /// that they are refused before their body is read.
+    pub connections: usize,
+    /// The command starting a worker, [`worker`] but in the tests.
+    pub worker: fn() -> process::Command,
+}
+
+/// `aoc serve-worker` from the running executable.
+pub fn worker() -> process::Command {
+    let exe = env::current_exe().unwrap_or_else(|_| PathBuf::from("aoc"));
+    let mut command = process::Command::new(exe);
+    command.arg("serve-worker");
+    command
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SolveRequest {
+    day: u8,
+    part: Option<u8>,
+    input: String,
+}
+
+/// What the server writes to the stdin of a worker: the request and the
+/// params set for its day, as a config file.
+#[derive(Debug, Serialize, Deserialize)]
+struct Job {
+    day: u8,
+    part: Option<u8>,
+    input: String,
+    params: String,
+}
+
+/// What `POST /solve` answers.
+#[derive(Debug, Serialize)]
+struct SolveResponse {
+    day: u8,
+    input_sha256: String,
+    parse_ns: u64,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    parse_stats: BTreeMap<String, serde_json::Value>,
+    parts: Vec<PartReport>,
+}
+
+/// A request that could not be answered, sent back as `{"error": message}`.
+/// Workers print it with its status.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Error {
+    status: u16,
+    #[serde(rename = "error")]
+    message: String,
+}
+
+impl Error {
+    fn new(status: u16, message: impl Into<String>) -> Self {
+        Error {
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+/// Gives back a place taken in a counter of [`Server`], however the request
+/// ends.
+struct Release<'a>(&'a AtomicUsize);
+
+impl Drop for Release<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Take a place in `counter` if it has fewer than `max`.
+fn acquire(counter: &AtomicUsize, max: usize) -> Option<Release<'_>> {
+    if counter.fetch_add(1, Ordering::SeqCst) >= max {
+        counter.fetch_sub(1, Ordering::SeqCst);
+        return None;
+    }
+    Some(Release(counter))
+}
+
+pub struct Server {
+    http: tiny_http::Server,
+    solvers: &'static [Solver],
+    config: Config,
+    /// Requests being answered.
+    open: AtomicUsize,
+    /// Workers running.
+    busy: AtomicUsize,
+}
+
+impl Server {
+    /// Listen on `addr`, port 0 picks a free one.
+    pub fn bind(addr: SocketAddr, solvers: &'static [Solver], config: Config) -> io::Result<Self> {
+        let http = tiny_http::Server::http(addr).map_err(io::Error::other)?;
+        Ok(Server {
+            http,
+            solvers,
+            config,
+            open: AtomicUsize::new(0),
+            busy: AtomicUsize::new(0),
+        })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.http
+            .server_addr()
+            .to_ip()
+            .expect("the server listens on an IP address")
+    }
+
+    /// Answer requests, each from its own thread, up to
+    /// [`Config::connections`] of them.
+    pub fn run(&self) {
+        thread::scope(|scope| {
+            for request in self.http.incoming_requests() {
+                let Some(release) = acquire(&self.open, self.config.connections) else {
+                    let busy = Error::new(503, "Too many requests at once, try again later");
+                    self.respond(request, Instant::now(), Err(busy));
+                    continue;
+                };
+                scope.spawn(move || {
+                    let _release = release;
+                    self.answer(request)
+                });
+            }
+        });
+    }
+
+    fn answer(&self, mut request: Request) {
+        let start = Instant::now();
+        let result = self.handle(&mut request);
+        self.respond(request, start, result);
+    }
+
+    fn respond(&self, request: Request, start: Instant, result: Result<String, Error>) {
+        let (status, body) = match result {
+            Ok(response) => (200, response),
+            Err(e) => (
+                e.status,
+                serde_json::json!({ "error": e.message }).to_string(),
+            ),
+        };
+        log::info!(
+            "{} {} {} in {:?}",
+            request.method(),
+            request.url(),
+            status,
+            start.elapsed()
+        );
+        let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+        let response = Response::from_string(body)
+            .with_status_code(status)
+            .with_header(content_type);
+        if let Err(e) = request.respond(response) {
+            log::warn!("cannot send the response: {}", e);
+        }
+    }
+
+    /// The JSON of the [`SolveResponse`], as the worker printed it.
+    fn handle(&self, request: &mut Request) -> Result<String, Error> {
+        if request.url() != "/solve" {
+            return Err(Error::new(404, format!("No route {}", request.url())));
+        }
+        if request.method() != &Method::Post {
+            return Err(Error::new(405, "Only POST /solve"));
+        }
+        let mut body = Vec::new();
+        request
+            .as_reader()
+            .take(MAX_BODY as u64 + 1)
+            .read_to_end(&mut body)
+            .map_err(|e| Error::new(400, format!("cannot read the request: {}", e)))?;
+        if body.len() > MAX_BODY {
+            return Err(Error::new(
+                413,
+                format!("Requests are limited to {} bytes", MAX_BODY),
+            ));
+        }
+        let request: SolveRequest = serde_json::from_slice(&body)
+            .map_err(|e| Error::new(400, format!("Invalid request, {}", e)))?;
+        self.solve(request)
+    }
+
+    /// Solve from a worker process, killed when the solver takes too long so
+    /// that it does not hold its worker forever.
+    fn solve(&self, request: SolveRequest) -> Result<String, Error> {
+        select(self.solvers, request.day, request.part)?;
+        let _release = acquire(&self.busy, self.config.workers)
+            .ok_or_else(|| Error::new(503, "Every worker is busy, try again later"))?;
+
+        let job = Job {
+            params: format!(
+                "[day_{:02}]\n{}",
+                request.day,
+                params::overrides(request.day)
+            ),
+            day: request.day,
+            part: request.part,
+            input: request.input,
+        };
+        let job = serde_json::to_string(&job).expect("jobs serialize to JSON");
+        let mut child = (self.config.worker)()
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|e| Error::new(500, format!("cannot start a worker: {}", e)))?;
+        let mut stdin = child
+            .stdin
+            .take()
+            .expect("the stdin of the worker is piped");
+        let mut stdout = child
+            .stdout
+            .take()
+            .expect("the stdout of the worker is piped");
+        let (sender, receiver) = mpsc::channel();
+        // Talked to from another thread, a stuck worker must not block the
+        // request past its timeout
+        thread::spawn(move || {
+            let _ = stdin.write_all(job.as_bytes());
+            drop(stdin);
+            let mut output = String::new();
+            let _ = stdout.read_to_string(&mut output);
+            let _ = sender.send(output);
+        });
+        let output = match receiver.recv_timeout(self.config.timeout) {
+            Ok(output) => output,
+            Err(e) => {
+                if let Err(e) = child.kill() {
+                    log::warn!("cannot kill the worker {}: {}", child.id(), e);
+                }
+                let _ = child.wait();
+                return Err(match e {
+                    RecvTimeoutError::Timeout => {
+                        Error::new(504, format!("No answer after {:?}", self.config.timeout))
+                    }
+                    // The thread talking to the worker died before reading it
+                    RecvTimeoutError::Disconnected => {
+                        Error::new(500, "The worker was lost before it answered")
+                    }
+                });
+            }
+        };
+        let status = child
+            .wait()
+            .map_err(|e| Error::new(500, format!("cannot wait for the worker: {}", e)))?;
+        // The solvers may have printed something before
+        let reply = output.lines().last().unwrap_or("");
+        if status.success() {
+            Ok(reply.to_string())
+        } else {
+            // A worker that did not print an error panicked
+            Err(serde_json::from_str(reply)
+                .unwrap_or_else(|_| Error::new(500, "The solver panicked")))
+        }
+    }
+}
+
+/// `aoc serve-worker`: solve the [`Job`] read from stdin and print the
+/// [`SolveResponse`] as the last line of stdout, or the [`Error`] and fail.
+pub fn work(solvers: &'static [Solver]) -> ExitCode {
+    let mut job = String::new();
+    let reply = io::stdin()
+        .read_to_string(&mut job)
+        .map_err(|e| Error::new(500, format!("cannot read the job: {}", e)))
+        .and_then(|_| {
+            serde_json::from_str(&job).map_err(|e| Error::new(500, format!("Invalid job, {}", e)))
+        })
+        .and_then(|job| run(solvers, job));
+    let (reply, code) = match reply {
+        Ok(response) => (serde_json::to_string(&response), ExitCode::SUCCESS),
+        Err(e) => (serde_json::to_string(&e), ExitCode::FAILURE),
+    };
+    println!("{}", reply.expect("replies serialize to JSON"));
+    code
+}
+
+fn run(solvers: &'static [Solver], job: Job) -> Result<SolveResponse, Error> {
+    let config = params::Config::parse(&job.params)
+        .map_err(|e| Error::new(500, format!("Invalid params, {}", e)))?;
+    params::set(config);
+    solve(job.day, &select(solvers, job.day, job.part)?, &job.input)
+}
+
+/// The implemented solvers asked for, both parts of the day without a part.
+fn select(
+    solvers: &'static [Solver],
+    day: u8,
+    part: Option<u8>,
+) -> Result<Vec<&'static Solver>, Error> {
+    let found = solvers
+        .iter()
+        .filter(|s| s.day == day && part.is_none_or(|part| part == s.part))
+        .collect::<Vec<_>>();
+    let name = match part {
+        Some(part) => format!("day {} part {}", day, part),
+        None => format!("day {}", day),
+    };
+    if found.is_empty() {
+        return Err(Error::new(404, format!("No solver for {}", name)));
+    }
+    let implemented = found
+        .into_iter()
+        .filter(|s| s.status == Status::Implemented)
+        .collect::<Vec<_>>();
+    if implemented.is_empty() {
+        return Err(Error::new(501, format!("{} is not implemented yet", name)));
+    }
+    Ok(implemented)
+}
+
+fn solve(day: u8, solvers: &[&Solver], input: &str) -> Result<SolveResponse, Error> {
+    aoc_common::stats::take();
+    let start = Instant::now();
+    let model = (solvers[0].parse)(input)
+        .map_err(|e| Error::new(422, format!("Invalid puzzle input, {}", e)))?;
+    let parse_ns = start.elapsed().as_nanos() as u64;
+    let parse_stats = aoc_common::stats::take();
+    let parts = solvers
+        .iter()
+        .map(|solver| {
+            let start = Instant::now();
+            let answer = (solver.solve)(model.as_ref());
+            PartReport {
+                part: solver.part,
+                answer,
+                solve_ns: start.elapsed().as_nanos() as u64,
+                expected: None,
+                cached: false,
+                stats: aoc_common::stats::take(),
+            }
+        })
+        .collect();
+    Ok(SolveResponse {
+        day,
+        input_sha256: input_hash(input),
+        parse_ns,
+        parse_stats,
+        parts,
+    })
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+    use std::{
+        any::Any,
+        io::Write,
+        net::TcpStream,
+        panic::{self, AssertUnwindSafe},
+    };
+
+    use aoc_common::parse::ParseError;
+    use indoc::indoc;
+
+    use crate::registry::SOLVERS;
+
+    /// Send a raw HTTP request, return the status and the JSON body.
+    fn send(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, serde_json::Value) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+
+    /// Run a server on a free port for the duration of `test`.
+    fn with_server(solvers: &'static [Solver], config: Config, test: impl FnOnce(SocketAddr)) {
+        let server = Server::bind("127.0.0.1:0".parse().unwrap(), solvers, config).unwrap();
+        let addr = server.local_addr();
+        thread::scope(|scope| {
+            scope.spawn(|| server.run());
+            // Stop the server even when the test fails, or the scope waits forever
+            let result = panic::catch_unwind(AssertUnwindSafe(|| test(addr)));
+            server.http.unblock();
+            if let Err(e) = result {
+                panic::resume_unwind(e);
+            }
+        });
+    }
+
+    const WORKER: &str = "AOC_TEST_WORKER";
+
+    /// The test binary running only [`worker`], with the solvers of the
+    /// registry or [`SLOW`].
+    fn test_worker(solvers: &str) -> process::Command {
+        let mut command = process::Command::new(env::current_exe().unwrap());
+        command
+            .args(["serve::tests::worker", "--exact", "--nocapture", "--quiet"])
+            .env(WORKER, solvers);
+        command
+    }
+
+    // Does nothing but when the tests start it as a worker
+    #[test]
+    fn worker() {
+        let solvers = match env::var(WORKER).as_deref() {
+            Ok("slow") => SLOW,
+            Ok(_) => SOLVERS,
+            Err(_) => return,
+        };
+        let code = work(solvers);
+        process::exit(if code == ExitCode::SUCCESS { 0 } else { 1 });
+    }
+
+    const CONFIG: Config = Config {
+        timeout: Duration::from_secs(10),
+        workers: 4,
+        connections: 8,
+        worker: || test_worker("registry"),
+    };
+
+    #[test]
+    fn test_solve() {
+        let input = indoc! {"
+            Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+            Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+            Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+            Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+        "};
+        with_server(SOLVERS, CONFIG, |addr| {
+            let request = serde_json::json!({ "day": 2, "input": input }).to_string();
+            let (status, response) = send(addr, "POST", "/solve", &request);
+            assert_eq!(status, 200);
+            assert_eq!(response["day"], 2);
+            assert_eq!(response["input_sha256"], input_hash(input));
+            let answers = response["parts"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|part| (part["part"].as_u64().unwrap(), part["answer"].clone()))
+                .collect::<Vec<_>>();
+            assert_eq!(answers, [(1, "8".into()), (2, "2286".into())]);
+
+            let request = serde_json::json!({ "day": 2, "part": 2, "input": input }).to_string();
+            let (status, response) = send(addr, "POST", "/solve", &request);
+            assert_eq!(status, 200);
+            assert_eq!(response["parts"].as_array().unwrap().len(), 1);
+            assert_eq!(response["parts"][0]["answer"], "2286");
+        });
+    }
+
+    #[test]
+    fn test_errors() {
+        with_server(SOLVERS, CONFIG, |addr| {
+            let error = |method, path, body: &str| {
+                let (status, response) = send(addr, method, path, body);
+                (status, response["error"].as_str().unwrap().to_string())
+            };
+            assert_eq!(error("GET", "/", "").0, 404);
+            assert_eq!(error("GET", "/solve", "").0, 405);
+            assert_eq!(error("POST", "/solve", "{\"day\": 2}").0, 400);
+            assert_eq!(
+                error("POST", "/solve", r#"{"day": 12, "input": ""}"#),
+                (404, "No solver for day 12".to_string())
+            );
+            assert_eq!(
+                error("POST", "/solve", r#"{"day": 21, "part": 2, "input": ""}"#),
+                (501, "day 21 part 2 is not implemented yet".to_string())
+            );
+            let (status, message) = error("POST", "/solve", r#"{"day": 2, "input": "Game one"}"#);
+            assert_eq!(status, 422);
+            assert!(
+                message.starts_with("Invalid puzzle input, line 1"),
+                "{}",
+                message
+            );
+            // One byte too many, all of it read by the server before it answers
+            let big = r#"{"day": 2, "input": ""}"#;
+            let big = big.replace(
+                "\"\"",
+                &format!("\"{}\"", "x".repeat(MAX_BODY + 1 - big.len())),
+            );
+            assert_eq!(big.len(), MAX_BODY + 1);
+            assert_eq!(error("POST", "/solve", &big).0, 413);
+        });
+    }
+
+    fn parse_any(input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(input.to_string()))
+    }
+
+    fn sleep(_: &dyn Any) -> String {
+        thread::sleep(Duration::from_secs(60));
+        "slept".to_string()
+    }
+
+    fn panic(_: &dyn Any) -> String {
+        panic!("this solver always panics")
+    }
+
+    const SLOW: &[Solver] = &[
+        Solver {
+            day: 1,
+            part: 1,
+            status: Status::Implemented,
+            version: 1,
+            params: &[],
+            check_params: |_| Ok(()),
+            parse: parse_any,
+            solve: sleep,
+        },
+        Solver {
+            day: 2,
+            part: 1,
+            status: Status::Implemented,
+            version: 1,
+            params: &[],
+            check_params: |_| Ok(()),
+            parse: parse_any,
+            solve: panic,
+        },
+    ];
+
+    #[test]
+    fn test_timeout() {
+        let config = Config {
+            timeout: Duration::from_millis(500),
+            workers: 1,
+            worker: || test_worker("slow"),
+            ..CONFIG
+        };
+        with_server(SLOW, config, |addr| {
+            let request = r#"{"day": 1, "input": ""}"#;
+            thread::scope(|scope| {
+                let first = scope.spawn(|| send(addr, "POST", "/solve", request));
+                thread::sleep(Duration::from_millis(200));
+                // The only worker is asleep
+                assert_eq!(send(addr, "POST", "/solve", request).0, 503);
+                let (status, response) = first.join().unwrap();
+                assert_eq!(status, 504);
+                assert_eq!(response["error"], "No answer after 500ms");
+            });
+            // Killed on timeout, the solver that would sleep for a minute
+            // left its worker to the next requests
+            for _ in 0..2 {
+                assert_eq!(send(addr, "POST", "/solve", request).0, 504);
+            }
+        });
+    }
+
+    #[test]
+    fn test_connections() {
+        let config = Config {
+            timeout: Duration::from_millis(500),
+            connections: 1,
+            worker: || test_worker("slow"),
+            ..CONFIG
+        };
+        with_server(SLOW, config, |addr| {
+            let request = r#"{"day": 1, "input": ""}"#;
+            thread::scope(|scope| {
+                let first = scope.spawn(|| send(addr, "POST", "/solve", request));
+                thread::sleep(Duration::from_millis(200));
+                // Refused even with workers left
+                assert_eq!(
+                    send(addr, "GET", "/", ""),
+                    (
+                        503,
+                        serde_json::json!({ "error": "Too many requests at once, try again later" })
+                    )
+                );
+                assert_eq!(first.join().unwrap().0, 504);
+            });
+            // The connection is released
+            assert_eq!(send(addr, "GET", "/", "").0, 404);
+        });
+    }
+
+    #[test]
+    fn test_panic() {
+        let config = Config {
+            workers: 1,
+            worker: || test_worker("slow"),
+            ..CONFIG
+        };
+        with_server(SLOW, config, |addr| {
+            let request = r#"{"day": 2, "input": ""}"#;
+            assert_eq!(
+                send(addr, "POST", "/solve", request),
+                (500, serde_json::json!({ "error": "The solver panicked" }))
+            );
+            // The worker is released
+            assert_eq!(send(addr, "POST", "/solve", request).0, 500);
+        });
+    }
+}
@@ -0,0 +1,474 @@
+//! `aoc view`: step through the simulation of a day in the terminal, back and
+//! forth, and look at what is under the cursor.
+
+use std::{
+    collections::HashMap,
+    io,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{
+    render::{Palette, Rgb},
+    simulation::Simulation,
+    Coord,
+};
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Ticks between two copies of the simulation kept to step back from.
+/// Stepping back replays at most this many ticks from the copy before.
+const CHECKPOINT_EVERY: usize = 64;
+
+/// The ticks of a simulation reached so far.
+pub struct History {
+    /// The simulation at ticks 0, `CHECKPOINT_EVERY`, `2 * CHECKPOINT_EVERY`...
+    checkpoints: Vec<Box<dyn Simulation>>,
+    current: Box<dyn Simulation>,
+    tick: usize,
+    /// The last tick, once the simulation got there.
+    end: Option<usize>,
+}
+
+impl History {
+    pub fn new(simulation: Box<dyn Simulation>) -> Self {
+        Self {
+            checkpoints: vec![simulation.clone()],
+            current: simulation,
+            tick: 0,
+            end: None,
+        }
+    }
+
+    pub fn current(&self) -> &dyn Simulation {
+        self.current.as_ref()
+    }
+
+    pub fn tick(&self) -> usize {
+        self.tick
+    }
+
+    pub fn end(&self) -> Option<usize> {
+        self.end
+    }
+
+    /// Move one tick forward, false at the end of the simulation.
+    pub fn forward(&mut self) -> bool {
+        if self.end == Some(self.tick) {
+            return false;
+        }
+        if !self.current.step() {
+            self.end = Some(self.tick);
+            return false;
+        }
+        self.tick += 1;
+        if self.tick == self.checkpoints.len() * CHECKPOINT_EVERY {
+            self.checkpoints.push(self.current.clone());
+        }
+        true
+    }
+
+    /// Move one tick back, false at the first tick.
+    pub fn back(&mut self) -> bool {
+        if self.tick == 0 {
+            return false;
+        }
+        self.goto(self.tick - 1);
+        true
+    }
+
+    /// Go to `tick`, or to the end when the simulation stops before it.
+    pub fn goto(&mut self, tick: usize) {
+        if tick < self.tick {
+            let checkpoint = (tick / CHECKPOINT_EVERY).min(self.checkpoints.len() - 1);
+            self.current = self.checkpoints[checkpoint].clone();
+            self.tick = checkpoint * CHECKPOINT_EVERY;
+        }
+        while self.tick < tick && self.forward() {}
+    }
+}
+
+/// What the viewer shows and how it reacts to keys, apart from the terminal.
+struct Viewer {
+    day: u8,
+    history: History,
+    cursor: Coord,
+    running: bool,
+    ticks_per_second: u32,
+    /// The tick typed after `g`, until Enter.
+    jump: Option<String>,
+    quit: bool,
+}
+
+const HELP: &str =
+    "n/p next/previous  space run/pause  +/- speed  g go to tick  arrows/hjkl cursor  q quit";
+
+impl Viewer {
+    fn new(day: u8, simulation: Box<dyn Simulation>) -> Self {
+        Self {
+            day,
+            history: History::new(simulation),
+            cursor: Coord::new(0, 0),
+            running: false,
+            ticks_per_second: 8,
+            jump: None,
+            quit: false,
+        }
+    }
+
+    fn handle(&mut self, key: KeyCode) {
+        if let Some(jump) = self.jump.as_mut() {
+            match key {
+                KeyCode::Char(c) if c.is_ascii_digit() => jump.push(c),
+                KeyCode::Backspace => {
+                    jump.pop();
+                }
+                KeyCode::Enter => {
+                    if let Ok(tick) = jump.parse() {
+                        self.history.goto(tick);
+                    }
+                    self.jump = None;
+                }
+                KeyCode::Esc => self.jump = None,
+                _ => {}
+            }
+            return;
+        }
+
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Char('n') | KeyCode::Char('.') => {
+                self.running = false;
+                self.history.forward();
+            }
+            KeyCode::Char('p') | KeyCode::Char(',') => {
+                self.running = false;
+                self.history.back();
+            }
+            KeyCode::Char(' ') => self.running = !self.running,
+            KeyCode::Char('+') => self.ticks_per_second = (self.ticks_per_second * 2).min(1024),
+            KeyCode::Char('-') => self.ticks_per_second = (self.ticks_per_second / 2).max(1),
+            KeyCode::Char('g') => {
+                self.running = false;
+                self.jump = Some(String::new());
+            }
+            KeyCode::Left | KeyCode::Char('h') => self.move_cursor(-1, 0),
+            KeyCode::Right | KeyCode::Char('l') => self.move_cursor(1, 0),
+            KeyCode::Up | KeyCode::Char('k') => self.move_cursor(0, -1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_cursor(0, 1),
+            _ => {}
+        }
+    }
+
+    fn move_cursor(&mut self, dx: isize, dy: isize) {
+        let map = self.history.current().map();
+        self.cursor = Coord::new(
+            (self.cursor.x + dx).clamp(0, map.width() as isize - 1),
+            (self.cursor.y + dy).clamp(0, map.height() as isize - 1),
+        );
+    }
+
+    /// Advance while running, stopping at the end.
+    fn on_tick(&mut self) {
+        if self.running && !self.history.forward() {
+            self.running = false;
+        }
+    }
+
+    fn header(&self) -> String {
+        let end = match self.history.end() {
+            Some(end) => format!(" of {}", end),
+            None => String::new(),
+        };
+        let state = if self.running {
+            format!("running at {}/s", self.ticks_per_second)
+        } else {
+            "paused".to_string()
+        };
+        format!(
+            "Day {}, tick {}{} ({}): {}",
+            self.day,
+            self.history.tick(),
+            end,
+            state,
+            self.history.current().status()
+        )
+    }
+
+    fn footer(&self) -> String {
+        match &self.jump {
+            Some(jump) => format!("Go to tick: {}_  (Enter to go, Esc to cancel)", jump),
+            None => HELP.to_string(),
+        }
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [header, body, inspect, footer] = *Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Min(3),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .split(frame.size())
+        else {
+            unreachable!("the layout has four parts");
+        };
+
+        let simulation = self.history.current();
+        let map = simulation.map();
+        // The map may change size from one tick to the next
+        self.cursor = Coord::new(
+            self.cursor.x.clamp(0, (map.width() as isize - 1).max(0)),
+            self.cursor.y.clamp(0, (map.height() as isize - 1).max(0)),
+        );
+
+        let block = Block::default().borders(Borders::ALL);
+        let area = block.inner(body);
+        let offset = |cursor: isize, size: usize, visible: u16| {
+            let visible = visible as isize;
+            (cursor - visible / 2).clamp(0, (size as isize - visible).max(0))
+        };
+        let (dx, dy) = (
+            offset(self.cursor.x, map.width(), area.width),
+            offset(self.cursor.y, map.height(), area.height),
+        );
+
+        let palette = Palette::default();
+        let highlights = simulation
+            .highlights()
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        let color = |c: Rgb| Color::Rgb(c.0, c.1, c.2);
+        let lines = (dy..map.height() as isize)
+            .take(area.height as usize)
+            .map(|y| {
+                (dx..map.width() as isize)
+                    .take(area.width as usize)
+                    .map(|x| {
+                        let coord = Coord::new(x, y);
+                        let tile = map[coord];
+                        let mut style = Style::default().fg(color(palette.color(tile)));
+                        if let Some(highlight) = highlights.get(&coord) {
+                            style = style.bg(color(*highlight)).fg(Color::Black);
+                        }
+                        if coord == self.cursor {
+                            style = style.add_modifier(Modifier::REVERSED);
+                        }
+                        Span::styled(tile.to_string(), style)
+                    })
+                    .collect::<Vec<_>>()
+                    .into()
+            })
+            .collect::<Vec<Line>>();
+
+        frame.render_widget(Paragraph::new(self.header()), header);
+        frame.render_widget(Paragraph::new(lines).block(block), body);
+        frame.render_widget(
+            Paragraph::new(simulation.inspect(self.cursor).unwrap_or_default()),
+            inspect,
+        );
+        frame.render_widget(
+            Paragraph::new(self.footer()).style(Style::default().add_modifier(Modifier::DIM)),
+            footer,
+        );
+    }
+}
+
+fn restore_terminal() -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen)
+}
+
+/// Show the viewer until the user quits.
+pub fn run(day: u8, simulation: Box<dyn Simulation>) -> io::Result<()> {
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    // Give the terminal back before a panic message is printed, or it would
+    // be lost with the alternate screen
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = restore_terminal();
+        hook(info);
+    }));
+
+    let result = event_loop(Viewer::new(day, simulation));
+    restore_terminal()?;
+    result
+}
+
+fn event_loop(mut viewer: Viewer) -> io::Result<()> {
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+    let mut last_tick = Instant::now();
+    while !viewer.quit {
+        terminal.draw(|frame| viewer.draw(frame))?;
+
+        let tick_rate = Duration::from_secs(1) / viewer.ticks_per_second;
+        let timeout = if viewer.running {
+            tick_rate.saturating_sub(last_tick.elapsed())
+        } else {
+            // Nothing changes until a key is pressed
+            Duration::from_secs(60)
+        };
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    viewer.handle(key.code);
+                }
+            }
+        }
+        if last_tick.elapsed() >= tick_rate {
+            viewer.on_tick();
+            last_tick = Instant::now();
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Grid;
+    use pretty_assertions::assert_eq;
+    use ratatui::backend::TestBackend;
+
+    /// Counts up to 100, drawn as the last digit of the count.
+    #[derive(Debug, Clone)]
+    struct Counter(usize);
+
+    impl Simulation for Counter {
+        fn status(&self) -> String {
+            format!("count {}", self.0)
+        }
+
+        fn step(&mut self) -> bool {
+            if self.0 == 100 {
+                return false;
+            }
+            self.0 += 1;
+            true
+        }
+
+        fn map(&self) -> Grid<char> {
+            Grid::new(3, 2, char::from_digit(self.0 as u32 % 10, 10).unwrap())
+        }
+
+        fn inspect(&self, coord: Coord) -> Option<String> {
+            Some(format!("({}, {}) of {}", coord.x, coord.y, self.0))
+        }
+    }
+
+    fn count(history: &History) -> String {
+        history.current().status()
+    }
+
+    #[test]
+    fn test_history() {
+        let mut history = History::new(Box::new(Counter(0)));
+        assert!(!history.back());
+        assert!(history.forward());
+        assert_eq!(
+            (history.tick(), count(&history)),
+            (1, "count 1".to_string())
+        );
+
+        history.goto(70);
+        assert_eq!(history.checkpoints.len(), 2);
+        assert!(history.back());
+        assert_eq!(
+            (history.tick(), count(&history)),
+            (69, "count 69".to_string())
+        );
+        history.goto(3);
+        assert_eq!(count(&history), "count 3");
+
+        // Past the end, it stops on the last tick
+        history.goto(500);
+        assert_eq!(history.tick(), 100);
+        assert_eq!(history.end(), Some(100));
+        assert!(!history.forward());
+        assert_eq!(count(&history), "count 100");
+    }
+
+    #[test]
+    fn test_keys() {
+        let mut viewer = Viewer::new(14, Box::new(Counter(0)));
+        for key in ['n', 'n', '.', 'p'] {
+            viewer.handle(KeyCode::Char(key));
+        }
+        assert_eq!(viewer.history.tick(), 2);
+
+        for key in "g42".chars() {
+            viewer.handle(KeyCode::Char(key));
+        }
+        viewer.handle(KeyCode::Backspace);
+        viewer.handle(KeyCode::Char('0'));
+        assert_eq!(
+            viewer.footer(),
+            "Go to tick: 40_  (Enter to go, Esc to cancel)"
+        );
+        viewer.handle(KeyCode::Enter);
+        assert_eq!(viewer.history.tick(), 40);
+        assert_eq!(viewer.footer(), HELP);
+
+        viewer.handle(KeyCode::Char(' '));
+        viewer.on_tick();
+        assert_eq!(viewer.history.tick(), 41);
+        viewer.history.goto(100);
+        viewer.on_tick();
+        assert!(!viewer.running);
+
+        // The cursor stays on the map
+        for key in [
+            KeyCode::Right,
+            KeyCode::Right,
+            KeyCode::Right,
+            KeyCode::Down,
+        ] {
+            viewer.handle(key);
+        }
+        viewer.handle(KeyCode::Char('k'));
+        assert_eq!(viewer.cursor, Coord::new(2, 0));
+
+        viewer.handle(KeyCode::Char('q'));
+        assert!(viewer.quit);
+    }
+
+    #[test]
+    fn test_draw() {
+        let mut viewer = Viewer::new(14, Box::new(Counter(0)));
+        viewer.handle(KeyCode::Char('n'));
+        viewer.handle(KeyCode::Right);
+        let mut terminal = Terminal::new(TestBackend::new(40, 7)).unwrap();
+        terminal.draw(|frame| viewer.draw(frame)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let line = |y| {
+            (0..buffer.area.width)
+                .map(|x| buffer.get(x, y).symbol())
+                .collect::<String>()
+        };
+        assert_eq!(line(0).trim_end(), "Day 14, tick 1 (paused): count 1");
+        assert_eq!(
+            line(1).trim_end(),
+            "┌──────────────────────────────────────┐"
+        );
+        assert_eq!(
+            line(2).trim_end(),
+            "│111                                   │"
+        );
+        assert_eq!(
+            line(3).trim_end(),
+            "│111                                   │"
+        );
+        assert_eq!(line(5).trim_end(), "(1, 0) of 1");
+        assert!(buffer.get(2, 2).modifier.contains(Modifier::REVERSED));
+    }
+}
@@ -0,0 +1,220 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use crate::parse::{finish, IResult, ParseError};
+use nom::{
+    branch::alt,
+    bytes::complete::take_till1,
+    character::complete::{alpha1, char, not_line_ending, space0, space1, u8},
+    combinator::{cut, eof, not, recognize, verify},
+    error::context,
+    multi::{many0, many1},
+    sequence::{pair, preceded, tuple},
+};
+
+/// Known answers for our own inputs, one `<day> <part> <answer>` per line.
+/// Empty lines and lines starting with `#` are ignored.
+pub const FILE_NAME: &str = "answers.txt";
+
+/// Answers keyed by day and part, or by example and part for the fixtures of
+/// a day. The answers of the examples can be for other values of the params
+/// than the puzzle's.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u8, u8, Vec<String>), String>);
+
+type Line<'a> = ((u8, u8, Vec<String>), &'a str);
+
+fn parse_line<'a>(
+    key: &'static str,
+    with_params: bool,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Line<'a>> {
+    move |input| {
+        let (input, day) = context(key, u8)(input)?;
+        let (input, part) = context("a part", preceded(space1, u8))(input)?;
+        if !with_params {
+            let (input, answer) = context(
+                "an answer",
+                preceded(
+                    space1,
+                    verify(not_line_ending, |a: &str| !a.trim().is_empty()),
+                ),
+            )(input)?;
+            return Ok((input, ((day, part, Vec::new()), answer)));
+        }
+        let (input, answer) = context(
+            "an answer",
+            preceded(space1, take_till1(|c: char| c.is_whitespace())),
+        )(input)?;
+        let (input, params) = many0(preceded(
+            pair(space1, not(eof)),
+            cut(context(
+                "a param, name=value",
+                recognize(tuple((
+                    many1(alt((alpha1, recognize(char('_'))))),
+                    char('='),
+                    take_till1(|c: char| c.is_whitespace()),
+                ))),
+            )),
+        ))(input)?;
+        let (input, _) = space0(input)?;
+        let params = params.into_iter().map(str::to_string).collect();
+        Ok((input, ((day, part, params), answer)))
+    }
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_keyed(input, "a day", false)
+    }
+
+    /// Parse `<example> <part> <answer>` lines, see [`crate::fixtures`]. The
+    /// answer can be followed by the `name=value` params it is for.
+    pub fn parse_examples(input: &str) -> Result<Self, ParseError> {
+        Self::parse_keyed(input, "an example", true)
+    }
+
+    fn parse_keyed(input: &str, key: &'static str, with_params: bool) -> Result<Self, ParseError> {
+        let mut answers = BTreeMap::new();
+        for (index, line) in input.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let on_line = |e| ParseError {
+                line: index + 1,
+                ..e
+            };
+            let (at, answer) = finish(line, parse_line(key, with_params)).map_err(on_line)?;
+            if answers.insert(at, answer.trim().to_string()).is_some() {
+                return Err(on_line(ParseError::at(
+                    line,
+                    line,
+                    format!("{}, part and params that are not listed yet", key),
+                )));
+            }
+        }
+        Ok(Answers(answers))
+    }
+
+    /// The answer for the puzzle's values of the params.
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part, Vec::new())).map(String::as_str)
+    }
+
+    /// The answers of an example by part, with the `name=value` params each
+    /// one is for.
+    pub fn of(&self, example: u8) -> impl Iterator<Item = (u8, &[String], &str)> {
+        self.0
+            .iter()
+            .filter(move |((key, _, _), _)| *key == example)
+            .map(|((_, part, params), answer)| (*part, params.as_slice(), answer.as_str()))
+    }
+}
+
+/// Find the answers file in the current directory or one of its parents.
+pub fn default_path() -> Option<PathBuf> {
+    crate::input::find_upwards(Path::new(FILE_NAME))
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(indoc!(
+            "
+            # day part answer
+            1 1 142
+
+            1 2 281
+            21 1  16
+            "
+        ))
+        .unwrap();
+        assert_eq!(answers.get(1, 1), Some("142"));
+        assert_eq!(answers.get(1, 2), Some("281"));
+        assert_eq!(answers.get(21, 1), Some("16"));
+        assert_eq!(answers.get(2, 1), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Answers::parse("1 1 142\n1 x 281\n"),
+            Err(ParseError::new(2, 3, "1 x 281", "a part"))
+        );
+        assert_eq!(
+            Answers::parse("1 1 142\n1 2\n"),
+            Err(ParseError::new(2, 4, "1 2", "an answer"))
+        );
+        assert_eq!(
+            Answers::parse("1 1 142\n1 1 143\n"),
+            Err(ParseError::new(
+                2,
+                1,
+                "1 1 143",
+                "a day, part and params that are not listed yet"
+            ))
+        );
+        assert_eq!(
+            Answers::parse_examples("x 1 142\n"),
+            Err(ParseError::new(1, 1, "x 1 142", "an example"))
+        );
+        assert_eq!(
+            Answers::parse_examples("1 2 1030 expansion 10\n"),
+            Err(ParseError::new(
+                1,
+                19,
+                "1 2 1030 expansion 10",
+                "a param, name=value"
+            ))
+        );
+        assert_eq!(
+            Answers::parse_examples("1 2 1030 expansion=10\n1 2 1031 expansion=10\n"),
+            Err(ParseError::new(
+                2,
+                1,
+                "1 2 1031 expansion=10",
+                "an example, part and params that are not listed yet"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_examples() {
+        let answers = Answers::parse_examples(indoc!(
+            "
+            # example part answer params
+            1 1 374
+            1 2 1030 expansion=10
+            1 2 8410  expansion=100
+            2 1 16 steps=6 feeders=[\"a\",\"b\"]
+            "
+        ))
+        .unwrap();
+        assert_eq!(answers.get(1, 1), Some("374"));
+        assert_eq!(answers.get(1, 2), None);
+        assert_eq!(
+            answers.of(1).collect::<Vec<_>>(),
+            vec![
+                (1, &[][..], "374"),
+                (2, &["expansion=10".to_string()][..], "1030"),
+                (2, &["expansion=100".to_string()][..], "8410"),
+            ]
+        );
+        assert_eq!(
+            answers.of(2).collect::<Vec<_>>(),
+            vec![(
+                1,
+                &["steps=6".to_string(), "feeders=[\"a\",\"b\"]".to_string()][..],
+                "16"
+            )]
+        );
+        assert_eq!(answers.of(3).count(), 0);
+    }
+}
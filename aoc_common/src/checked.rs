@@ -0,0 +1,176 @@
+//! Arithmetic for the spots where a solver could overflow on an unusual input.
+//! With the `checked` feature every operation is checked, and an overflow
+//! panics with the operands and where it happened in the solver. Without it
+//! they are the plain operators: a panic in debug builds, silently wrapping
+//! in release.
+//!
+//! ```sh
+//! cargo run --release -p aoc --features checked -- run 11
+//! ```
+
+use std::{
+    fmt::Display,
+    ops::{Add, Mul, Sub},
+    panic::Location,
+};
+
+/// Whether the arithmetic is checked, the answers of checked and unchecked
+/// builds may differ.
+pub const ENABLED: bool = cfg!(feature = "checked");
+
+/// The integers the operations work on, up to 64 bits and `i128`.
+pub trait Int:
+    Copy + Display + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn to_i128(self) -> i128;
+    fn checked_from_i128(value: i128) -> Option<Self>;
+    /// The same as `value as Self`.
+    fn wrapping_from_i128(value: i128) -> Self;
+}
+
+macro_rules! int {
+    ($($t:ty),*) => {
+        $(impl Int for $t {
+            const ZERO: Self = 0;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn checked_from_i128(value: i128) -> Option<Self> {
+                Self::try_from(value).ok()
+            }
+
+            fn wrapping_from_i128(value: i128) -> Self {
+                value as Self
+            }
+        })*
+    };
+}
+
+int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+/// Called from a `match` and not from closures, which would lose the
+/// location of the caller.
+#[cold]
+#[track_caller]
+fn overflow(operation: String) -> ! {
+    panic!("overflow in {} at {}", operation, Location::caller())
+}
+
+#[inline]
+#[track_caller]
+pub fn add<T: Int>(a: T, b: T) -> T {
+    if ENABLED {
+        match a.checked_add(b) {
+            Some(result) => result,
+            None => overflow(format!("{} + {}", a, b)),
+        }
+    } else {
+        a + b
+    }
+}
+
+#[inline]
+#[track_caller]
+pub fn sub<T: Int>(a: T, b: T) -> T {
+    if ENABLED {
+        match a.checked_sub(b) {
+            Some(result) => result,
+            None => overflow(format!("{} - {}", a, b)),
+        }
+    } else {
+        a - b
+    }
+}
+
+#[inline]
+#[track_caller]
+pub fn mul<T: Int>(a: T, b: T) -> T {
+    if ENABLED {
+        match a.checked_mul(b) {
+            Some(result) => result,
+            None => overflow(format!("{} * {}", a, b)),
+        }
+    } else {
+        a * b
+    }
+}
+
+/// The sum of the values, `Iterator::sum` when unchecked.
+#[track_caller]
+pub fn sum<T: Int>(values: impl IntoIterator<Item = T>) -> T {
+    // A loop and not a fold, the closure would hide the caller's location
+    let mut total = T::ZERO;
+    for value in values {
+        total = add(total, value);
+    }
+    total
+}
+
+/// `value` as another integer type, checked that it fits with the feature
+/// and an `as` cast without.
+#[inline]
+#[track_caller]
+pub fn cast<T: Int, U: Int>(value: T) -> U {
+    if ENABLED {
+        match U::checked_from_i128(value.to_i128()) {
+            Some(result) => result,
+            None => overflow(format!("{} as {}", value, std::any::type_name::<U>())),
+        }
+    } else {
+        U::wrapping_from_i128(value.to_i128())
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_in_range() {
+        assert_eq!(add(2usize, 3), 5);
+        assert_eq!(sub(2isize, 3), -1);
+        assert_eq!(mul(999_999usize, 140), 139_999_860);
+        assert_eq!(sum([1u64, 2, 3]), 6);
+        assert_eq!(sum(Vec::<usize>::new()), 0);
+        assert_eq!(cast::<i128, usize>(42), 42);
+        assert_eq!(cast::<isize, u8>(255), 255);
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn test_overflow() {
+        let message = |f: fn()| {
+            let payload = std::panic::catch_unwind(f).unwrap_err();
+            payload.downcast_ref::<String>().unwrap().clone()
+        };
+        let line = line!() + 1;
+        let overflow = message(|| _ = mul(u64::MAX / 2, 3));
+        let expected = format!("overflow in {} * 3 at {}:{}:", u64::MAX / 2, file!(), line);
+        assert!(overflow.starts_with(&expected), "{}", overflow);
+        assert!(message(|| _ = sum([usize::MAX, 1])).starts_with("overflow in"));
+        assert!(message(|| _ = sub(0u32, 1)).starts_with("overflow in 0 - 1 at "));
+        assert!(message(|| _ = cast::<i128, usize>(-1)).starts_with("overflow in -1 as usize"));
+        assert!(message(|| _ = cast::<i64, u8>(256)).starts_with("overflow in 256 as u8"));
+    }
+}
@@ -0,0 +1,157 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::Direction;
+
+/// A position on a 2D map. `x` grows to the right and `y` grows downward,
+/// so (0, 0) is the top-left corner of a puzzle input.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Ord, PartialOrd, Hash)]
+pub struct Coord {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Coord {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// Move one step in the given direction.
+    pub fn advance(&self, direction: Direction) -> Self {
+        *self + direction.offset()
+    }
+
+    pub fn manhattan(&self, other: &Coord) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Rotate by a quarter turn clockwise around the origin (as seen on screen,
+    /// with y pointing down).
+    pub fn rotate_right(&self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Rotate by a quarter turn counterclockwise around the origin (as seen on
+    /// screen, with y pointing down).
+    pub fn rotate_left(&self) -> Self {
+        Self {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+}
+
+impl From<(isize, isize)> for Coord {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Add<Coord> for Coord {
+    type Output = Coord;
+    fn add(self, rhs: Coord) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl AddAssign<Coord> for Coord {
+    fn add_assign(&mut self, rhs: Coord) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Coord> for Coord {
+    type Output = Coord;
+    fn sub(self, rhs: Coord) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl SubAssign<Coord> for Coord {
+    fn sub_assign(&mut self, rhs: Coord) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<isize> for Coord {
+    type Output = Coord;
+    fn mul(self, rhs: isize) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl Neg for Coord {
+    type Output = Coord;
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_add_coords() {
+        let a: Coord = (1, 2).into();
+        let b: Coord = (3, 4).into();
+        assert_eq!(a + b, (4, 6).into());
+    }
+
+    #[test]
+    fn test_sub_mul_neg() {
+        let a: Coord = (1, 2).into();
+        let b: Coord = (3, 5).into();
+        assert_eq!(b - a, (2, 3).into());
+        assert_eq!(a * 3, (3, 6).into());
+        assert_eq!(-a, (-1, -2).into());
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, (4, 7).into());
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn test_manhattan() {
+        let a: Coord = (1, 6).into();
+        let b: Coord = (5, 11).into();
+        assert_eq!(a.manhattan(&b), 9);
+        assert_eq!(b.manhattan(&a), 9);
+        assert_eq!(a.manhattan(&a), 0);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let right: Coord = (1, 0).into();
+        assert_eq!(right.rotate_right(), (0, 1).into());
+        assert_eq!(right.rotate_right().rotate_right(), (-1, 0).into());
+        assert_eq!(right.rotate_left(), (0, -1).into());
+        assert_eq!(right.rotate_left().rotate_right(), right);
+    }
+
+    #[test]
+    fn test_advance() {
+        let a: Coord = (1, 1).into();
+        assert_eq!(a.advance(Direction::Right), (2, 1).into());
+        assert_eq!(a.advance(Direction::Down), (1, 2).into());
+        assert_eq!(a.advance(Direction::Left), (0, 1).into());
+        assert_eq!(a.advance(Direction::Up), (1, 0).into());
+    }
+}
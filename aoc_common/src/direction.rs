@@ -0,0 +1,67 @@
+use crate::Coord;
+
+/// The four moves on a map, listed clockwise starting on the right.
+///
+/// The discriminant is the index of the matching neighbour returned by
+/// [`get_neighbours`](crate::get_neighbours), so `neighbours[Direction::Up as usize]`
+/// is the tile above.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub enum Direction {
+    Right = 0,
+    Down = 1,
+    Left = 2,
+    Up = 3,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+        Direction::Up,
+    ];
+
+    pub fn offset(&self) -> Coord {
+        match self {
+            Direction::Right => Coord::new(1, 0),
+            Direction::Down => Coord::new(0, 1),
+            Direction::Left => Coord::new(-1, 0),
+            Direction::Up => Coord::new(0, -1),
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(*self as usize + 3) % 4]
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self::ALL[(*self as usize + 2) % 4]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Right.turn_right(), Direction::Down);
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Right.turn_left(), Direction::Up);
+        assert_eq!(Direction::Down.opposite(), Direction::Up);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+    }
+
+    #[test]
+    fn test_offset_matches_rotation() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_right().offset(), d.offset().rotate_right());
+            assert_eq!(d.turn_left().offset(), d.offset().rotate_left());
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! The examples of the puzzle texts, kept as data in `day_XX/fixtures` so the
+//! tests, the runner and the benchmarks can all use them: `example_N.txt`
+//! holds the input of example `N` and `answers.txt` the expected answers, one
+//! `<example> <part> <answer>` per line. An example can be run with other
+//! values of the params than the puzzle's by listing them after its answer, as
+//! in `1 2 1030 expansion=10`.
+
+use std::path::{Path, PathBuf};
+
+use crate::{
+    answers::Answers,
+    input::find_upwards,
+    params::{self, Config},
+    Solution,
+};
+
+pub const ANSWERS: &str = "answers.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub number: u8,
+    pub path: PathBuf,
+}
+
+/// The fixtures of a day, in order, with their expected answers.
+#[derive(Debug, Default)]
+pub struct Fixtures {
+    pub examples: Vec<Fixture>,
+    pub answers: Answers,
+}
+
+impl Fixtures {
+    pub fn get(&self, number: u8) -> Option<&Fixture> {
+        self.examples.iter().find(|f| f.number == number)
+    }
+}
+
+fn relative_dir(day: u8) -> PathBuf {
+    PathBuf::from(format!("day_{:02}", day)).join("fixtures")
+}
+
+/// Find `day_XX/fixtures` in the current directory or one of its parents.
+pub fn dir(day: u8) -> Option<PathBuf> {
+    let answers = find_upwards(&relative_dir(day).join(ANSWERS))?;
+    answers.parent().map(Path::to_path_buf)
+}
+
+fn example_number(path: &Path) -> Option<u8> {
+    let name = path.file_name()?.to_str()?;
+    name.strip_prefix("example_")?
+        .strip_suffix(".txt")?
+        .parse()
+        .ok()
+}
+
+/// Discover the fixtures of a day. A day without a fixtures directory has
+/// none, which is not an error.
+pub fn load(day: u8) -> Result<Fixtures, String> {
+    let Some(dir) = dir(day) else {
+        return Ok(Fixtures::default());
+    };
+    let read_error =
+        |path: &Path, e: std::io::Error| format!("cannot read {}: {}", path.display(), e);
+
+    let answers_path = dir.join(ANSWERS);
+    let content =
+        std::fs::read_to_string(&answers_path).map_err(|e| read_error(&answers_path, e))?;
+    let answers = Answers::parse_examples(&content)
+        .map_err(|e| format!("Invalid answers in {}, {}", answers_path.display(), e))?;
+
+    let mut examples = std::fs::read_dir(&dir)
+        .map_err(|e| read_error(&dir, e))?
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let number = example_number(&path)?;
+            Some(Fixture { number, path })
+        })
+        .collect::<Vec<Fixture>>();
+    examples.sort_by_key(|f| f.number);
+
+    Ok(Fixtures { examples, answers })
+}
+
+/// The same input with the line endings people end up with: CRLF, no final
+/// newline, a UTF-8 BOM. The answers must not change.
+fn variants(input: &str) -> Vec<(&'static str, String)> {
+    vec![
+        ("", input.to_string()),
+        (" with CRLF", input.replace('\n', "\r\n")),
+        (
+            " without final newline",
+            input.trim_end_matches('\n').to_string(),
+        ),
+        (" with BOM", format!("\u{feff}{}", input)),
+    ]
+}
+
+/// The params of a day set to the `name=value` of an answer.
+fn config<S: Solution>(values: &[String]) -> Result<Config, String> {
+    let mut config = Config::default();
+    for value in values {
+        let (name, value) = value.split_once('=').expect("checked when parsed");
+        config.set(S::DAY, name, value)?;
+    }
+    config.check(S::DAY, S::PARAMS, S::check_params)?;
+    Ok(config)
+}
+
+/// Run every fixture of `S` and panic with the list of wrong answers. Only
+/// the parts with an expected answer are solved, each with the params listed
+/// with its answer. Each fixture is also run with CRLF line endings, without
+/// its final newline and with a BOM.
+pub fn check<S: Solution>() {
+    let fixtures = load(S::DAY).unwrap_or_else(|e| panic!("{}", e));
+    assert!(
+        !fixtures.examples.is_empty(),
+        "no fixtures found for day {}",
+        S::DAY
+    );
+
+    let mut failures = Vec::new();
+    for fixture in fixtures.examples.iter() {
+        let input = std::fs::read_to_string(&fixture.path)
+            .unwrap_or_else(|e| panic!("cannot read {}: {}", fixture.path.display(), e));
+        for (variant, input) in variants(&input) {
+            let name = format!("{}{}", fixture.path.display(), variant);
+            for (part, values, expected) in fixtures.answers.of(fixture.number) {
+                let name = match values {
+                    [] => format!("{} part {}", name, part),
+                    _ => format!("{} part {} with {}", name, part, values.join(" ")),
+                };
+                let config = match config::<S>(values) {
+                    Ok(config) => config,
+                    Err(e) => {
+                        failures.push(format!("{}: {}", name, e));
+                        continue;
+                    }
+                };
+                let answer = params::with(config, || {
+                    let model = S::parse(&input).map_err(|e| e.to_string())?;
+                    match part {
+                        1 => Ok(S::part1(&model).to_string()),
+                        2 => Ok(S::part2(&model).to_string()),
+                        _ => Err(format!("there is no part {}", part)),
+                    }
+                });
+                match answer {
+                    Ok(answer) if answer == expected => {}
+                    Ok(answer) => {
+                        failures.push(format!("{}: expected {}, got {}", name, expected, answer))
+                    }
+                    Err(e) => failures.push(format!("{}: {}", name, e)),
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_example_number() {
+        assert_eq!(example_number(Path::new("fixtures/example_3.txt")), Some(3));
+        assert_eq!(example_number(Path::new("fixtures/answers.txt")), None);
+        assert_eq!(example_number(Path::new("fixtures/example_x.txt")), None);
+    }
+
+    #[test]
+    fn test_no_fixtures() {
+        assert_eq!(load(99).unwrap().examples, vec![]);
+    }
+}
@@ -0,0 +1,297 @@
+//! Exact geometry on lattice polygons, for the loops and trenches that are
+//! too big to flood fill. A polygon is the list of its vertices in order, the
+//! last one being joined back to the first (repeating the first vertex at the
+//! end is harmless). Every product is done in `i128` so nothing overflows for
+//! `isize` coordinates.
+
+use std::cmp::Ordering;
+
+use crate::{math::gcd, Coord};
+
+/// Which points a self-overlapping polygon contains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// Points the polygon winds around at least once.
+    NonZero,
+    /// Points crossed into an odd number of times from the outside.
+    EvenOdd,
+}
+
+/// The cross product of `a -> b` and `a -> p`: positive when `p` is on the
+/// right of `a -> b` as seen on screen (y pointing down), negative on the
+/// left, 0 when the three points are aligned.
+pub fn cross(a: Coord, b: Coord, p: Coord) -> i128 {
+    let (ax, ay) = (a.x as i128, a.y as i128);
+    (b.x as i128 - ax) * (p.y as i128 - ay) - (p.x as i128 - ax) * (b.y as i128 - ay)
+}
+
+/// [`cross`] reduced to its sign.
+pub fn orientation(a: Coord, b: Coord, p: Coord) -> Ordering {
+    cross(a, b, p).cmp(&0)
+}
+
+/// Whether `p` lies on the segment from `a` to `b`, ends included.
+pub fn on_segment(a: Coord, b: Coord, p: Coord) -> bool {
+    cross(a, b, p) == 0
+        && p.x >= a.x.min(b.x)
+        && p.x <= a.x.max(b.x)
+        && p.y >= a.y.min(b.y)
+        && p.y <= a.y.max(b.y)
+}
+
+fn edges(polygon: &[Coord]) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+}
+
+pub fn on_boundary(polygon: &[Coord], point: Coord) -> bool {
+    edges(polygon).any(|(a, b)| on_segment(a, b, point))
+}
+
+/// How many times the polygon turns around `point`, counterclockwise as seen
+/// on screen being positive. Meaningless for points on the boundary.
+pub fn winding_number(polygon: &[Coord], point: Coord) -> i32 {
+    edges(polygon)
+        .map(|(a, b)| {
+            if a.y <= point.y && b.y > point.y && cross(a, b, point) > 0 {
+                1
+            } else if a.y > point.y && b.y <= point.y && cross(a, b, point) < 0 {
+                -1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+/// How many edges a ray from `point` to the right crosses. Meaningless for
+/// points on the boundary.
+pub fn crossings(polygon: &[Coord], point: Coord) -> usize {
+    edges(polygon)
+        .filter(|(a, b)| {
+            (a.y > point.y) != (b.y > point.y) && (cross(*a, *b, point) > 0) == (a.y < b.y)
+        })
+        .count()
+}
+
+/// Whether `point` is strictly inside the polygon, points on the boundary
+/// are not.
+pub fn contains(polygon: &[Coord], point: Coord, rule: FillRule) -> bool {
+    if on_boundary(polygon, point) {
+        return false;
+    }
+    match rule {
+        FillRule::NonZero => winding_number(polygon, point) != 0,
+        FillRule::EvenOdd => crossings(polygon, point) % 2 == 1,
+    }
+}
+
+/// Twice the area with the shoelace formula, positive when the vertices go
+/// clockwise as seen on screen. Doubled so it stays an integer.
+pub fn signed_double_area(polygon: &[Coord]) -> i128 {
+    edges(polygon)
+        .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+        .sum()
+}
+
+pub fn double_area(polygon: &[Coord]) -> i128 {
+    signed_double_area(polygon).abs()
+}
+
+/// Number of lattice points on the edges.
+pub fn boundary_points(polygon: &[Coord]) -> i128 {
+    edges(polygon)
+        .map(|(a, b)| gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y)) as i128)
+        .sum()
+}
+
+/// Number of lattice points strictly inside a simple polygon, from Pick's
+/// theorem: `A = I + B / 2 - 1`.
+pub fn interior_points(polygon: &[Coord]) -> i128 {
+    (double_area(polygon) - boundary_points(polygon)) / 2 + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, NEIGHBOURS};
+    use pretty_assertions::assert_eq;
+
+    fn polygon(vertices: &[(isize, isize)]) -> Vec<Coord> {
+        vertices.iter().map(|v| Coord::from(*v)).collect()
+    }
+
+    /// Rectilinear test shapes, small enough to flood fill.
+    fn shapes() -> Vec<Vec<Coord>> {
+        vec![
+            // Rectangle
+            polygon(&[(0, 0), (4, 0), (4, 3), (0, 3)]),
+            // L, counterclockwise
+            polygon(&[(0, 0), (0, 6), (5, 6), (5, 4), (2, 4), (2, 0)]),
+            // U with a one wide gap
+            polygon(&[
+                (0, 0),
+                (2, 0),
+                (2, 4),
+                (4, 4),
+                (4, 0),
+                (6, 0),
+                (6, 6),
+                (0, 6),
+            ]),
+            // Spiral
+            polygon(&[
+                (0, 0),
+                (8, 0),
+                (8, 8),
+                (2, 8),
+                (2, 2),
+                (6, 2),
+                (6, 6),
+                (4, 6),
+                (4, 4),
+                (5, 4),
+                (5, 3),
+                (3, 3),
+                (3, 7),
+                (7, 7),
+                (7, 1),
+                (0, 1),
+            ]),
+            // Day 18 example trench
+            polygon(&[
+                (0, 0),
+                (6, 0),
+                (6, 5),
+                (4, 5),
+                (4, 7),
+                (6, 7),
+                (6, 9),
+                (1, 9),
+                (1, 7),
+                (0, 7),
+                (0, 5),
+                (2, 5),
+                (2, 2),
+                (0, 2),
+            ]),
+        ]
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Unknown,
+        Boundary,
+        Outside,
+    }
+
+    /// Draw the edges on a grid with a margin, then flood the outside from a
+    /// corner. What the flood did not reach is inside.
+    fn flood_fill(polygon: &[Coord]) -> (Coord, Grid<Tile>) {
+        let min = Coord::new(
+            polygon.iter().map(|c| c.x).min().unwrap() - 1,
+            polygon.iter().map(|c| c.y).min().unwrap() - 1,
+        );
+        let width = polygon.iter().map(|c| c.x).max().unwrap() - min.x + 2;
+        let height = polygon.iter().map(|c| c.y).max().unwrap() - min.y + 2;
+        let mut grid = Grid::new(width as usize, height as usize, Tile::Unknown);
+
+        for (a, b) in edges(polygon) {
+            let step = Coord::new((b.x - a.x).signum(), (b.y - a.y).signum());
+            let mut c = a;
+            while c != b {
+                grid[c - min] = Tile::Boundary;
+                c += step;
+            }
+        }
+
+        let mut stack = vec![Coord::new(0, 0)];
+        while let Some(c) = stack.pop() {
+            if grid.get(c) != Some(&Tile::Unknown) {
+                continue;
+            }
+            grid[c] = Tile::Outside;
+            stack.extend(NEIGHBOURS.iter().map(|n| c + *n));
+        }
+        (min, grid)
+    }
+
+    #[test]
+    fn test_against_flood_fill() {
+        for shape in shapes() {
+            let (min, grid) = flood_fill(&shape);
+            let count = |tile| grid.iter().filter(|(_, t)| **t == tile).count() as i128;
+            assert_eq!(
+                boundary_points(&shape),
+                count(Tile::Boundary),
+                "{:?}",
+                shape
+            );
+            assert_eq!(interior_points(&shape), count(Tile::Unknown), "{:?}", shape);
+
+            for (c, tile) in grid.iter() {
+                let point = c + min;
+                let inside = *tile == Tile::Unknown;
+                assert_eq!(on_boundary(&shape, point), *tile == Tile::Boundary);
+                assert_eq!(contains(&shape, point, FillRule::NonZero), inside);
+                assert_eq!(contains(&shape, point, FillRule::EvenOdd), inside);
+            }
+
+            // Neither the direction nor a repeated first vertex matter
+            let mut reversed = shape.clone();
+            reversed.reverse();
+            assert_eq!(interior_points(&reversed), count(Tile::Unknown));
+            assert_eq!(signed_double_area(&reversed), -signed_double_area(&shape));
+            let mut closed = shape.clone();
+            closed.push(shape[0]);
+            assert_eq!(interior_points(&closed), count(Tile::Unknown));
+        }
+    }
+
+    #[test]
+    fn test_diagonal_edges() {
+        // Pick against counting points one by one, on a shape a flood fill
+        // would leak out of
+        let shape = polygon(&[(0, 0), (9, 3), (4, 8), (-2, 5)]);
+        let brute = (-3..11)
+            .flat_map(|x| (-1..10).map(move |y| Coord::new(x, y)))
+            .filter(|p| contains(&shape, *p, FillRule::NonZero))
+            .count() as i128;
+        assert_eq!(interior_points(&shape), brute);
+        assert_eq!(boundary_points(&shape), 3 + 5 + 3 + 1);
+        assert_eq!(double_area(&shape), 96);
+    }
+
+    #[test]
+    fn test_fill_rules() {
+        // The same square twice: wound around twice, crossed twice
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        let twice = [square.clone(), square].concat();
+        let center = Coord::new(2, 2);
+        assert_eq!(winding_number(&twice, center).abs(), 2);
+        assert_eq!(crossings(&twice, center), 2);
+        assert!(contains(&twice, center, FillRule::NonZero));
+        assert!(!contains(&twice, center, FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn test_orientation() {
+        let (a, b) = (Coord::new(0, 0), Coord::new(4, 0));
+        assert_eq!(orientation(a, b, Coord::new(1, 1)), Ordering::Greater);
+        assert_eq!(orientation(a, b, Coord::new(1, -1)), Ordering::Less);
+        assert_eq!(orientation(a, b, Coord::new(9, 0)), Ordering::Equal);
+        assert!(on_segment(a, b, Coord::new(4, 0)));
+        assert!(!on_segment(a, b, Coord::new(5, 0)));
+
+        // Far beyond what f64 represents exactly
+        let big = isize::MAX / 2;
+        let (a, b) = (Coord::new(-big, -big), Coord::new(big, big - 1));
+        assert_eq!(
+            orientation(a, b, Coord::new(big - 1, big - 1)),
+            Ordering::Greater
+        );
+        assert_eq!(orientation(a, b, Coord::new(big, big - 2)), Ordering::Less);
+    }
+}
@@ -0,0 +1,507 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use nom::error::{ErrorKind, ParseError as _, VerboseError};
+
+use crate::{
+    parse::{failure, parse_input, IResult, ParseError},
+    Coord, CoordMap, NEIGHBOURS, NEIGHBOURS8,
+};
+
+/// A rectangular map stored row by row, so looking a tile up is an index
+/// computation instead of a hash. (0, 0) is the top-left tile, like for
+/// [`Coord`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from its tiles, row after row.
+    ///
+    /// Panics when `cells` does not hold a whole number of rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not make rows of {}",
+            cells.len(),
+            width
+        );
+        let height = cells.len() / width;
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Build a grid by calling `tile` for every coordinate, row after row.
+    ///
+    /// Panics when `width` is 0.
+    pub fn from_fn(width: usize, height: usize, mut tile: impl FnMut(Coord) -> T) -> Self {
+        assert!(width > 0, "a grid needs at least one column");
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Coord::new(x as isize, y as isize)))
+            .map(&mut tile)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        (0..self.width as isize).contains(&coord.x) && (0..self.height as isize).contains(&coord.y)
+    }
+
+    fn index_of(&self, coord: Coord) -> Option<usize> {
+        self.contains(coord)
+            .then(|| coord.y as usize * self.width + coord.x as usize)
+    }
+
+    fn coord_of(&self, index: usize) -> Coord {
+        Coord::new((index % self.width) as isize, (index / self.width) as isize)
+    }
+
+    /// The tile at `coord`, `None` when outside of the grid.
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.index_of(coord).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index_of(coord).map(|i| &mut self.cells[i])
+    }
+
+    /// Every tile with its coordinates, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (self.coord_of(i), v))
+    }
+
+    /// Coordinates of the first tile, row after row, matching `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Coord> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.coord_of(i))
+    }
+
+    fn neighbours_at<'a>(
+        &'a self,
+        coord: Coord,
+        offsets: &'a [Coord],
+    ) -> impl Iterator<Item = (Coord, &'a T)> + 'a {
+        offsets.iter().filter_map(move |offset| {
+            let neighbour = coord + *offset;
+            self.get(neighbour).map(|v| (neighbour, v))
+        })
+    }
+
+    /// The 4 neighbours inside the grid, clockwise starting on the right.
+    pub fn neighbours(&self, coord: Coord) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        self.neighbours_at(coord, &NEIGHBOURS)
+    }
+
+    /// The 8 neighbours inside the grid (diagonals included), clockwise
+    /// starting on the right.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        self.neighbours_at(coord, &NEIGHBOURS8)
+    }
+
+    /// Panics when `y` is outside of the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks_exact(self.width)
+    }
+
+    /// Panics when `x` is outside of the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(x < self.width, "column {} outside of the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// A grid of the same size with `f` applied to every tile.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` x `height` grid filled with `value`.
+    ///
+    /// Panics when `width` is 0.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        assert!(width > 0, "a grid needs at least one column");
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    fn remap(&self, width: usize, height: usize, source: impl Fn(Coord) -> Coord) -> Self {
+        Self::from_fn(width, height, |coord| self[source(coord)].clone())
+    }
+
+    /// Swap rows and columns.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |c| Coord::new(c.y, c.x))
+    }
+
+    /// Rotate by a quarter turn clockwise, as seen on screen.
+    pub fn rotate_right(&self) -> Self {
+        let last_row = self.height as isize - 1;
+        self.remap(self.height, self.width, |c| Coord::new(c.y, last_row - c.x))
+    }
+
+    /// Rotate by a quarter turn counterclockwise, as seen on screen.
+    pub fn rotate_left(&self) -> Self {
+        let last_column = self.width as isize - 1;
+        self.remap(self.height, self.width, |c| {
+            Coord::new(last_column - c.y, c.x)
+        })
+    }
+
+    /// Mirror left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let last_column = self.width as isize - 1;
+        self.remap(self.width, self.height, |c| {
+            Coord::new(last_column - c.x, c.y)
+        })
+    }
+
+    /// Mirror top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let last_row = self.height as isize - 1;
+        self.remap(self.width, self.height, |c| Coord::new(c.x, last_row - c.y))
+    }
+}
+
+impl Grid<char> {
+    /// Parse a whole puzzle input that is nothing but a map.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        parse_input(input, char_grid)
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("{:?} outside of the grid", coord))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{:?} outside of the grid", coord))
+    }
+}
+
+impl<V: Copy> CoordMap<V> for Grid<V> {
+    fn tile(&self, coord: &Coord) -> Option<V> {
+        self.get(*coord).copied()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for tile in row {
+                write!(f, "{}", tile)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse the lines of a char map up to a blank line or the end of the input.
+/// Every row must be as wide as the first one.
+pub fn char_grid(input: &str) -> IResult<&str, Grid<char>> {
+    let mut rest = input;
+    let mut width = None;
+    let mut cells = Vec::new();
+
+    while let Some((row, remaining)) = rest.split_once('\n') {
+        if row.is_empty() {
+            break;
+        }
+        let length = row.chars().count();
+        match width {
+            None => width = Some(length),
+            Some(width) if width != length => {
+                // Point at the first missing or extra tile
+                let offset = row
+                    .char_indices()
+                    .nth(width.min(length))
+                    .map_or(row.len(), |(i, _)| i);
+                return Err(failure(&rest[offset..], "a row as wide as the first one"));
+            }
+            Some(_) => (),
+        }
+        cells.extend(row.chars());
+        rest = remaining;
+    }
+
+    match width {
+        Some(width) => Ok((rest, Grid::from_vec(width, cells))),
+        None => Err(nom::Err::Error(VerboseError::from_error_kind(
+            input,
+            ErrorKind::Many1,
+        ))),
+    }
+}
+
+/// [`char_grid`] of a map made of `tiles` only. Any other char fails at its
+/// position, wrap the parser in a `context` saying which tiles are expected.
+pub fn char_grid_of(tiles: &'static str) -> impl Fn(&str) -> IResult<&str, Grid<char>> {
+    move |input: &str| {
+        let (rest, grid) = char_grid(input)?;
+        let map = &input[..input.len() - rest.len()];
+        match map.find(|c| c != '\n' && !tiles.contains(c)) {
+            None => Ok((rest, grid)),
+            Some(offset) => Err(nom::Err::Failure(VerboseError::from_error_kind(
+                &input[offset..],
+                ErrorKind::OneOf,
+            ))),
+        }
+    }
+}
+
+/// [`char_grid_of`] `tiles` with exactly one `start` tile. A map without it
+/// fails at its first tile expecting `missing`, a second one fails where it
+/// is expecting `again`.
+pub fn char_grid_with_start(
+    tiles: &'static str,
+    start: char,
+    missing: &'static str,
+    again: &'static str,
+) -> impl Fn(&str) -> IResult<&str, Grid<char>> {
+    move |input: &str| {
+        let (rest, grid) = char_grid_of(tiles)(input)?;
+        let map = &input[..input.len() - rest.len()];
+        let mut starts = map.match_indices(start).map(|(i, _)| &input[i..]);
+        match (starts.next(), starts.next()) {
+            (Some(_), None) => Ok((rest, grid)),
+            (None, _) => Err(failure(input, missing)),
+            (Some(_), Some(second)) => Err(failure(second, again)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_neighbours;
+    use pretty_assertions::assert_eq;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n").unwrap()
+    }
+
+    fn text(grid: &Grid<char>) -> String {
+        grid.to_string()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(text(&grid), "abc\ndef\n");
+        assert_eq!(
+            Grid::parse("abc\nde\n"),
+            Err(ParseError::new(
+                2,
+                3,
+                "de",
+                "a row as wide as the first one"
+            ))
+        );
+        assert_eq!(
+            Grid::parse("abc\ndefg\n"),
+            Err(ParseError::new(
+                2,
+                4,
+                "defg",
+                "a row as wide as the first one"
+            ))
+        );
+        assert_eq!(
+            Grid::parse(""),
+            Err(ParseError::new(1, 1, "", "at least one item"))
+        );
+    }
+
+    #[test]
+    fn test_char_grid_stops_at_blank_line() {
+        let (rest, grid) = char_grid("ab\ncd\n\nef\n").unwrap();
+        assert_eq!(text(&grid), "ab\ncd\n");
+        assert_eq!(rest, "\nef\n");
+    }
+
+    #[test]
+    fn test_char_grid_of() {
+        let (rest, grid) = char_grid_of("#.")("#.\n.#\n\nab\n").unwrap();
+        assert_eq!(text(&grid), "#.\n.#\n");
+        assert_eq!(rest, "\nab\n");
+        assert_eq!(
+            parse_input("#.\n.O\n", char_grid_of("#.")),
+            Err(ParseError::new(2, 2, ".O", "one of the allowed characters"))
+        );
+        assert_eq!(
+            parse_input("#.\n.\n", char_grid_of("#.")),
+            Err(ParseError::new(2, 2, ".", "a row as wide as the first one"))
+        );
+    }
+
+    #[test]
+    fn test_char_grid_with_start() {
+        let parser = || char_grid_with_start("#.S", 'S', "a start", "a single start");
+        let (rest, grid) = parser()("#S\n..\n").unwrap();
+        assert_eq!((text(&grid).as_str(), rest), ("#S\n..\n", ""));
+        assert_eq!(
+            parse_input("#.\n..\n", parser()),
+            Err(ParseError::new(1, 1, "#.", "a start"))
+        );
+        assert_eq!(
+            parse_input("#S\n.S\n", parser()),
+            Err(ParseError::new(2, 2, ".S", "a single start"))
+        );
+        assert_eq!(
+            parse_input("#S\n.O\n", parser()),
+            Err(ParseError::new(2, 2, ".O", "one of the allowed characters"))
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = grid();
+        assert_eq!(grid.get(Coord::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Coord::new(3, 0)), None);
+        assert_eq!(grid.get(Coord::new(0, 2)), None);
+        assert_eq!(grid.get(Coord::new(-1, 0)), None);
+        assert_eq!(grid.get(Coord::new(0, -1)), None);
+
+        grid[Coord::new(1, 0)] = 'x';
+        assert_eq!(grid[Coord::new(1, 0)], 'x');
+        assert_eq!(grid.position(|c| *c == 'e'), Some(Coord::new(1, 1)));
+        assert_eq!(grid.position(|c| *c == 'z'), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the grid")]
+    fn test_index_outside() {
+        let _ = grid()[Coord::new(3, 0)];
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one column")]
+    fn test_new_without_columns() {
+        Grid::new(0, 3, '.');
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one column")]
+    fn test_from_fn_without_columns() {
+        Grid::from_fn(0, 3, |_| '.');
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours(Coord::new(0, 0)).collect::<Vec<_>>(),
+            vec![(Coord::new(1, 0), &'b'), (Coord::new(0, 1), &'d')]
+        );
+        assert_eq!(
+            grid.neighbours8(Coord::new(1, 1))
+                .map(|(_, v)| *v)
+                .collect::<String>(),
+            "fdabc"
+        );
+        // Usable wherever the hashed maps were
+        assert_eq!(
+            get_neighbours(&grid, Coord::new(2, 1)),
+            vec![
+                None,
+                None,
+                Some((Coord::new(1, 1), 'e')),
+                Some((Coord::new(2, 0), 'c'))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&['a', 'b', 'c'], &['d', 'e', 'f']]
+        );
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(grid.iter().nth(4), Some((Coord::new(1, 1), &'e')));
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = grid();
+        assert_eq!(text(&grid.transpose()), "ad\nbe\ncf\n");
+        assert_eq!(text(&grid.rotate_right()), "da\neb\nfc\n");
+        assert_eq!(text(&grid.rotate_left()), "cf\nbe\nad\n");
+        assert_eq!(text(&grid.flip_horizontal()), "cba\nfed\n");
+        assert_eq!(text(&grid.flip_vertical()), "def\nabc\n");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+        assert_eq!(
+            grid.rotate_right().rotate_right(),
+            grid.flip_horizontal().flip_vertical()
+        );
+    }
+
+    #[test]
+    fn test_new_and_map() {
+        let grid = Grid::new(2, 3, 0u8);
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid.map(|v| v + 1).to_string(), "11\n11\n11\n");
+        assert_eq!(
+            Grid::from_fn(3, 1, |c| c.x),
+            Grid::from_vec(3, vec![0, 1, 2])
+        );
+    }
+}
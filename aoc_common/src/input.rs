@@ -0,0 +1,145 @@
+use std::{
+    env, fmt,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where the puzzle input of a day comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    /// `day_XX/input.txt`, looked up from the current directory and its parents.
+    Default(u8),
+}
+
+impl Source {
+    /// Build the source from an optional `--input` value, `-` meaning stdin.
+    pub fn new(day: u8, path: Option<PathBuf>) -> Self {
+        match path {
+            None => Source::Default(day),
+            Some(p) if p == Path::new("-") => Source::Stdin,
+            Some(p) => Source::File(p),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let error = |error| InputError {
+            source: self.clone(),
+            error,
+        };
+        match self {
+            Source::File(path) => std::fs::read_to_string(path).map_err(error),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map_err(error)?;
+                Ok(input)
+            }
+            Source::Default(day) => {
+                let path = default_path(*day).ok_or_else(|| {
+                    error(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        "not found in the current directory or its parents",
+                    ))
+                })?;
+                std::fs::read_to_string(path).map_err(error)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+            Source::Default(day) => write!(f, "{}", default_relative_path(*day).display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: Source,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot read puzzle input from {}: {}",
+            self.source, self.error
+        )?;
+        if let Source::Default(_) = self.source {
+            write!(f, " (use --input <path>, or --input - to read stdin)")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+fn default_relative_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("day_{:02}", day)).join("input.txt")
+}
+
+/// Find `relative` in the current directory or one of its parents, so the
+/// binaries work from the workspace root as well as from a day's crate.
+pub fn find_upwards(relative: &Path) -> Option<PathBuf> {
+    let cwd = env::current_dir().ok()?;
+    cwd.ancestors()
+        .map(|dir| dir.join(relative))
+        .find(|path| path.is_file())
+}
+
+/// Find `day_XX/input.txt` in the current directory or one of its parents.
+pub fn default_path(day: u8) -> Option<PathBuf> {
+    find_upwards(&default_relative_path(day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_source_new() {
+        assert_eq!(Source::new(5, None), Source::Default(5));
+        assert_eq!(Source::new(5, Some(PathBuf::from("-"))), Source::Stdin);
+        assert_eq!(
+            Source::new(5, Some(PathBuf::from("my_input.txt"))),
+            Source::File(PathBuf::from("my_input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_read_file() {
+        let path = env::temp_dir().join("aoc_common_test_read_file.txt");
+        std::fs::write(&path, "1abc2\n").unwrap();
+        assert_eq!(Source::File(path.clone()).read().unwrap(), "1abc2\n");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let err = Source::File(PathBuf::from("does/not/exist.txt"))
+            .read()
+            .unwrap_err();
+        assert_eq!(err.error.kind(), io::ErrorKind::NotFound);
+        assert!(err
+            .to_string()
+            .starts_with("cannot read puzzle input from does/not/exist.txt"));
+
+        let err = Source::Default(99).read().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "cannot read puzzle input from day_99/input.txt: not found in the current \
+             directory or its parents (use --input <path>, or --input - to read stdin)"
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! Helpers shared by all the days, so that each solution holds only what is
+//! particular to its puzzle.
+//!
+//! - [`solution`]: the [`Solution`] trait every day implements, and `main`
+//!   for their binaries.
+//! - [`input`]: finding and reading the puzzle inputs.
+//! - [`parse`]: the [`parse::ParseError`] the parsers report bad input with.
+//! - [`params`]: values the days can be tuned with at runtime.
+//! - [`fixtures`] and [`answers`]: the examples of the puzzle texts and the
+//!   expected answers, for the tests and the runner.
+//! - [`snapshot`]: expected text of rendered maps, for the tests.
+//! - [`coord`], [`direction`], [`grid`] and [`map`]: coordinates,
+//!   directions, the dense [`Grid`] and the map utilities.
+//! - [`search`]: path finding over implicit graphs.
+//! - [`math`]: gcd, lcm and the Chinese Remainder Theorem.
+//! - [`checked`]: arithmetic checked for overflows with the `checked`
+//!   feature.
+//! - [`geometry`]: lattice polygons, their areas and the points inside.
+//! - [`render`]: PPM and SVG pictures of grids.
+//! - [`simulation`]: puzzles that `aoc view` steps through.
+//! - [`stats`]: values solvers report along with their answers.
+//! - [`logging`]: the logging setup.
+
+pub mod answers;
+pub mod checked;
+pub mod coord;
+pub mod direction;
+pub mod fixtures;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod logging;
+pub mod map;
+pub mod math;
+pub mod params;
+pub mod parse;
+pub mod render;
+pub mod search;
+pub mod simulation;
+pub mod snapshot;
+pub mod solution;
+pub mod stats;
+
+pub use coord::Coord;
+pub use direction::Direction;
+pub use grid::Grid;
+pub use map::{
+    get_neighbours, get_neighbours8, trace_map, CoordMap, TextMap, NEIGHBOURS, NEIGHBOURS8,
+};
+pub use solution::Solution;
@@ -0,0 +1,52 @@
+use log::LevelFilter;
+
+/// Environment variable read when no `-v` is given, with the `RUST_LOG` syntax,
+/// e.g. `AOC_LOG=debug` or `AOC_LOG=day_20=trace`.
+pub const ENV_VAR: &str = "AOC_LOG";
+
+/// The level matching a number of `-v` flags, `None` when there are none.
+pub fn level(verbosity: u8) -> Option<LevelFilter> {
+    match verbosity {
+        0 => None,
+        1 => Some(LevelFilter::Info),
+        2 => Some(LevelFilter::Debug),
+        _ => Some(LevelFilter::Trace),
+    }
+}
+
+/// Send the solvers' logs to stderr. `-v` flags win over `AOC_LOG`, and without
+/// either only errors show up, so stdout is left with the answer.
+pub fn init(verbosity: u8) {
+    let mut builder = env_logger::Builder::new();
+    builder
+        .format_timestamp(None)
+        .filter_level(LevelFilter::Error);
+    match level(verbosity) {
+        Some(level) => {
+            builder.filter_level(level);
+        }
+        None => {
+            if let Ok(filters) = std::env::var(ENV_VAR) {
+                builder.parse_filters(&filters);
+            }
+        }
+    }
+    // Fails when a logger is already set, the first one is as good
+    let _ = builder.try_init();
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_level() {
+        assert_eq!(level(0), None);
+        assert_eq!(level(1), Some(LevelFilter::Info));
+        assert_eq!(level(2), Some(LevelFilter::Debug));
+        assert_eq!(level(3), Some(LevelFilter::Trace));
+        assert_eq!(level(9), Some(LevelFilter::Trace));
+    }
+}
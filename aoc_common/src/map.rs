@@ -0,0 +1,195 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt, io,
+};
+
+use crate::Coord;
+
+/// Offsets of the 4 neighbours, clockwise starting on the right.
+pub const NEIGHBOURS: [Coord; 4] = [
+    Coord::new(1, 0),
+    Coord::new(0, 1),
+    Coord::new(-1, 0),
+    Coord::new(0, -1),
+];
+
+/// Offsets of the 8 neighbours (diagonals included), clockwise starting on the right.
+pub const NEIGHBOURS8: [Coord; 8] = [
+    Coord::new(1, 0),
+    Coord::new(1, 1),
+    Coord::new(0, 1),
+    Coord::new(-1, 1),
+    Coord::new(-1, 0),
+    Coord::new(-1, -1),
+    Coord::new(0, -1),
+    Coord::new(1, -1),
+];
+
+/// A map from coordinates to tiles.
+pub trait CoordMap<V> {
+    fn tile(&self, coord: &Coord) -> Option<V>;
+}
+
+impl<V: Copy> CoordMap<V> for HashMap<Coord, V> {
+    fn tile(&self, coord: &Coord) -> Option<V> {
+        self.get(coord).copied()
+    }
+}
+
+impl<V: Copy> CoordMap<V> for BTreeMap<Coord, V> {
+    fn tile(&self, coord: &Coord) -> Option<V> {
+        self.get(coord).copied()
+    }
+}
+
+fn neighbours_at<V>(
+    grid: &impl CoordMap<V>,
+    coord: Coord,
+    offsets: &[Coord],
+) -> Vec<Option<(Coord, V)>> {
+    offsets
+        .iter()
+        .map(|nc| grid.tile(&(coord + *nc)).map(|v| (coord + *nc, v)))
+        .collect()
+}
+
+// Get all neighbours clockwise, None when outside of the map
+pub fn get_neighbours<V>(grid: &impl CoordMap<V>, coord: Coord) -> Vec<Option<(Coord, V)>> {
+    neighbours_at(grid, coord, &NEIGHBOURS)
+}
+
+// Get all neighbours including diagonals clockwise, None when outside of the map
+pub fn get_neighbours8<V>(grid: &impl CoordMap<V>, coord: Coord) -> Vec<Option<(Coord, V)>> {
+    neighbours_at(grid, coord, &NEIGHBOURS8)
+}
+
+/// A map drawn as text, `.` where nothing is drawn. Each layer is drawn on
+/// top of the previous ones, and its tiles out of the map are left out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextMap {
+    rows: Vec<Vec<char>>,
+}
+
+impl TextMap {
+    pub fn new(width: usize, height: usize) -> Self {
+        TextMap {
+            rows: vec![vec!['.'; width]; height],
+        }
+    }
+
+    /// Draw the tiles, origin (0, 0) at the top-left corner.
+    pub fn layer(mut self, tiles: impl IntoIterator<Item = (usize, usize, char)>) -> Self {
+        for (x, y, v) in tiles {
+            if let Some(tile) = self.rows.get_mut(y).and_then(|row| row.get_mut(x)) {
+                *tile = v;
+            }
+        }
+        self
+    }
+
+    /// Write the rows, each ending with a newline.
+    pub fn write(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        for row in &self.rows {
+            for c in row {
+                out.write_char(*c)?;
+            }
+            out.write_char('\n')?;
+        }
+        Ok(())
+    }
+
+    pub fn write_io(&self, out: &mut impl io::Write) -> io::Result<()> {
+        write!(out, "{}", self)
+    }
+}
+
+impl fmt::Display for TextMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f)
+    }
+}
+
+/// Log a map at trace level, under a title. Maps are big, they are not even
+/// built when nobody will see them.
+pub fn trace_map(title: &str, map: impl FnOnce() -> TextMap) {
+    if !log::log_enabled!(log::Level::Trace) {
+        return;
+    }
+    // A single record, so it isn't interleaved
+    log::trace!("{}\n{}", title, map().to_string().trim_end_matches('\n'));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn grid() -> HashMap<Coord, char> {
+        ["ab", "cd"]
+            .iter()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(move |(x, c)| (Coord::new(x as isize, y as isize), c))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_get_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            get_neighbours(&grid, Coord::new(0, 0)),
+            vec![
+                Some((Coord::new(1, 0), 'b')),
+                Some((Coord::new(0, 1), 'c')),
+                None,
+                None
+            ]
+        );
+        let btree = grid.into_iter().collect::<BTreeMap<Coord, char>>();
+        assert_eq!(
+            get_neighbours(&btree, Coord::new(1, 1)),
+            vec![
+                None,
+                None,
+                Some((Coord::new(0, 1), 'c')),
+                Some((Coord::new(1, 0), 'b'))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_text_map() {
+        let map = TextMap::new(4, 2)
+            .layer([(0, 0, 'X'), (1, 0, 'X'), (3, 1, 'X')])
+            .layer([(1, 0, 'O'), (4, 0, 'O'), (0, 2, 'O')]);
+        assert_eq!(map.to_string(), "XO..\n...X\n");
+
+        let mut text = String::new();
+        map.write(&mut text).unwrap();
+        let mut bytes = Vec::new();
+        map.write_io(&mut bytes).unwrap();
+        assert_eq!(text.as_bytes(), bytes);
+        assert_eq!(TextMap::new(0, 0).to_string(), "");
+    }
+
+    #[test]
+    fn test_get_neighbours8() {
+        let grid = grid();
+        assert_eq!(
+            get_neighbours8(&grid, Coord::new(0, 0)),
+            vec![
+                Some((Coord::new(1, 0), 'b')),
+                Some((Coord::new(1, 1), 'd')),
+                Some((Coord::new(0, 1), 'c')),
+                None,
+                None,
+                None,
+                None,
+                None
+            ]
+        );
+    }
+}
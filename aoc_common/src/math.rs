@@ -0,0 +1,213 @@
+//! Number theory for the puzzles that combine cycles: gcd and lcm that report
+//! overflows instead of wrapping, extended Euclid, modular inverses and the
+//! Chinese Remainder Theorem for moduli that share factors.
+
+use std::ops::{Div, Rem};
+
+/// The unsigned integers gcd and lcm work on, up to `u128`.
+pub trait Unsigned: Copy + Eq + Div<Output = Self> + Rem<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! unsigned {
+    ($($t:ty),*) => {
+        $(impl Unsigned for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+        })*
+    };
+}
+
+unsigned!(u8, u16, u32, u64, u128, usize);
+
+pub fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, `None` when it does not fit in `T`. Dividing
+/// before multiplying keeps the intermediate value no larger than the result.
+pub fn lcm<T: Unsigned>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple of all the values, 1 for none.
+pub fn lcm_all<T: Unsigned>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::ONE, |acc, value| lcm(acc, value))
+}
+
+/// `(g, x, y)` with `g = gcd(a, b)` and `a * x + b * y = g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x = 1 (mod modulus)`, `None` when `a`
+/// and `modulus` share a factor.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    assert!(modulus > 0, "modulus must be positive, got {}", modulus);
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// `a * b % modulus` for `a` and `b` in `0..modulus`, without overflowing
+/// when the product does not fit.
+fn mul_mod(a: i128, b: i128, modulus: i128) -> i128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+    // Below 2^127, so doubling fits in a u128
+    let (mut a, mut b, modulus) = (a as u128, b as u128, modulus as u128);
+    let mut product = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            product = (product + a) % modulus;
+        }
+        a = (a << 1) % modulus;
+        b >>= 1;
+    }
+    product as i128
+}
+
+/// Solve `x = remainder (mod modulus)` for all the `(remainder, modulus)`
+/// pairs. The moduli don't have to be coprime. Returns the smallest
+/// non-negative `x` and the lcm of the moduli, every other solution being
+/// `x` plus a multiple of it. `None` when the congruences contradict each
+/// other.
+///
+/// # Panics
+///
+/// When the lcm of the moduli does not fit in an `i128`.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    let (mut x, mut modulus) = (0i128, 1i128);
+    for (remainder, n) in congruences {
+        assert!(n > 0, "modulus must be positive, got {}", n);
+        let remainder = remainder.rem_euclid(n);
+        // modulus * p = g (mod n)
+        let (g, p, _) = extended_gcd(modulus, n);
+        let difference = remainder - x;
+        if difference % g != 0 {
+            return None;
+        }
+        let step = n / g;
+        let k = mul_mod((difference / g).rem_euclid(step), p.rem_euclid(step), step);
+        let combined = modulus
+            .checked_mul(step)
+            .unwrap_or_else(|| panic!("the lcm of the moduli up to {} overflows", n));
+        // k < step and x < modulus, so both fit below combined
+        x = (x + modulus * k).rem_euclid(combined);
+        modulus = combined;
+    }
+    Some((x, modulus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12u32, 18), 6);
+        assert_eq!(gcd(0u8, 7), 7);
+        assert_eq!(lcm(4usize, 6), Some(12));
+        assert_eq!(lcm(0u64, 6), Some(0));
+        assert_eq!(lcm_all([2u64, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all(Vec::<u16>::new()), Some(1));
+    }
+
+    #[test]
+    fn test_lcm_overflow() {
+        // a * b overflows but the lcm fits
+        let big = u64::MAX / 3;
+        assert_eq!(lcm(big, big), Some(big));
+        assert_eq!(lcm(u8::MAX, 2), None);
+        assert_eq!(lcm_all([u64::MAX, u64::MAX - 1]), None);
+        assert_eq!(
+            lcm_all([u64::MAX as u128, u64::MAX as u128 - 1]),
+            Some(u64::MAX as u128 * (u64::MAX as u128 - 1))
+        );
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (46, 240), (-240, 46), (17, 0), (0, 5)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i128);
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli sharing a factor
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        // Negative remainders are offsets before the start of the cycle
+        assert_eq!(crt([(-1, 5), (0, 3)]), Some((9, 15)));
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(3, 7), (3, 7)]), Some((3, 7)));
+    }
+
+    #[test]
+    fn test_crt_large_moduli() {
+        // The moduli and their lcm fit, not the products along the way
+        let (m, n) = ((1i128 << 61) - 1, (1i128 << 64) - 59);
+        let x = (1i128 << 100) + 12345;
+        assert_eq!(crt([(x % m, m), (x % n, n)]), Some((x, m * n)));
+        assert_eq!(mul_mod(n - 1, n - 2, n), 2);
+        assert_eq!(crt([(1, n), (2, n)]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_crt_overflow() {
+        let n = (1i128 << 64) - 59;
+        crt([(0, n), (0, n - 2), (0, n - 4)]);
+    }
+
+    #[test]
+    fn test_crt_brute_force() {
+        for a in 0..6 {
+            for b in 0..10 {
+                let expected = (0..30).find(|x| x % 6 == a && x % 10 == b);
+                assert_eq!(crt([(a, 6), (b, 10)]).map(|s| s.0), expected);
+            }
+        }
+    }
+}
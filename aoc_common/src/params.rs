@@ -0,0 +1,479 @@
+//! Values the solvers can be tuned with at runtime, like the expansion of the
+//! universe of day 11 or the moves of the crucibles of day 17. Each day
+//! declares its params with their puzzle defaults in [`Solution::PARAMS`],
+//! and `aoc.toml` or `--param name=value` override them:
+//!
+//! ```toml
+//! [day_11]
+//! expansion = 100
+//! ```
+//!
+//! The binaries [`set`] the overrides once at startup, after checking that
+//! the solvers can read them: of the type of the default, and in the range
+//! each param accepts. The solvers [`get`] the values from any thread. The
+//! example fixtures run [`with`] their own values instead, on their thread
+//! only.
+
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    env,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+use serde::de::DeserializeOwned;
+use toml::{Table, Value};
+
+use crate::{input::find_upwards, Solution};
+
+/// The config file, looked up from the current directory and its parents.
+pub const FILE: &str = "aoc.toml";
+
+/// A value a day can be tuned with.
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+    pub name: &'static str,
+    /// The value of the puzzle, in TOML: `64`, `"rx"`, `["sh", "mz"]`.
+    pub default: &'static str,
+    pub doc: &'static str,
+    /// What a value of the type of the default must also be, [`any`] value
+    /// or a count [`at_least`] some number for instance. Says what it
+    /// expected otherwise.
+    pub check: fn(&Value) -> Result<(), String>,
+}
+
+impl Param {
+    pub fn default_value(&self) -> Value {
+        parse_value(self.default)
+            .unwrap_or_else(|e| panic!("invalid default of the param {}: {}", self.name, e))
+    }
+}
+
+/// A TOML value. Bare words are strings, `--param output=rx` need not be
+/// quoted.
+fn parse_value(value: &str) -> Result<Value, String> {
+    match format!("value = {}", value).parse::<Table>() {
+        Ok(mut table) if table.len() == 1 => Ok(table.remove("value").unwrap()),
+        _ if !value.is_empty() && value.chars().all(|c| c.is_alphanumeric() || c == '_') => {
+            Ok(Value::String(value.to_string()))
+        }
+        _ => Err(format!("invalid value {}", value)),
+    }
+}
+
+/// Any value of the type of the default.
+pub fn any(_value: &Value) -> Result<(), String> {
+    Ok(())
+}
+
+/// An integer from `MIN` that fits in a `usize`.
+pub fn at_least<const MIN: usize>(value: &Value) -> Result<(), String> {
+    match value.as_integer().map(usize::try_from) {
+        Some(Ok(n)) if n >= MIN => Ok(()),
+        _ => Err(format!("an integer from {}", MIN)),
+    }
+}
+
+/// A list of one value or more.
+pub fn non_empty(value: &Value) -> Result<(), String> {
+    match value.as_array() {
+        Some(values) if !values.is_empty() => Ok(()),
+        _ => Err("a list of one value or more".to_string()),
+    }
+}
+
+fn same_type(value: &Value, default: &Value) -> bool {
+    match (value, default) {
+        (Value::Array(values), Value::Array(defaults)) => match defaults.first() {
+            Some(default) => values.iter().all(|v| same_type(v, default)),
+            None => true,
+        },
+        _ => value.type_str() == default.type_str(),
+    }
+}
+
+/// The values set for each day, overriding the defaults.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    days: BTreeMap<u8, Table>,
+}
+
+impl Config {
+    /// A `[day_XX]` table of `name = value` per day.
+    pub fn parse(content: &str) -> Result<Config, String> {
+        let table = content.parse::<Table>().map_err(|e| e.to_string())?;
+        let mut config = Config::default();
+        for (key, value) in table {
+            let day = key
+                .strip_prefix("day_")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("expected a [day_XX] table, not {}", key))?;
+            let Value::Table(values) = value else {
+                return Err(format!("{} is not a table", key));
+            };
+            config.days.insert(day, values);
+        }
+        Ok(config)
+    }
+
+    /// The file given, or `$AOC_CONFIG`, or `aoc.toml` if there is one.
+    pub fn load(path: Option<&Path>) -> Result<Config, String> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => match env::var_os("AOC_CONFIG") {
+                Some(path) => PathBuf::from(path),
+                None => match find_upwards(Path::new(FILE)) {
+                    Some(path) => path,
+                    None => return Ok(Config::default()),
+                },
+            },
+        };
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        Config::parse(&content).map_err(|e| format!("Invalid {}, {}", path.display(), e))
+    }
+
+    /// Set a param of a day from a `--param` value.
+    pub fn set(&mut self, day: u8, name: &str, value: &str) -> Result<(), String> {
+        let value = parse_value(value).map_err(|e| format!("{} for {}", e, name))?;
+        self.days
+            .entry(day)
+            .or_default()
+            .insert(name.to_string(), value);
+        Ok(())
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.days.keys().copied()
+    }
+
+    /// Check that the values set for `day` are params it declares, of the
+    /// type of their default and passing their [`Param::check`], then that
+    /// they go together with `check_params`, the
+    /// [`Solution::check_params`] of the day.
+    pub fn check(
+        &self,
+        day: u8,
+        params: &[Param],
+        check_params: fn(&Values) -> Result<(), String>,
+    ) -> Result<(), String> {
+        for (name, value) in self.days.get(&day).into_iter().flatten() {
+            let Some(param) = params.iter().find(|p| p.name == name) else {
+                let names = params.iter().map(|p| p.name).collect::<Vec<_>>();
+                if names.is_empty() {
+                    return Err(format!("Day {} has no params, {} is unknown", day, name));
+                }
+                return Err(format!(
+                    "Day {} has no param {}, only {}",
+                    day,
+                    name,
+                    names.join(", ")
+                ));
+            };
+            check_value(day, param, value)?;
+        }
+        check_params(&self.values(day, params))
+    }
+
+    /// The values set for a day.
+    pub fn day(&self, day: u8) -> Table {
+        self.days.get(&day).cloned().unwrap_or_default()
+    }
+
+    /// The values of the params of a day, the defaults of those not set.
+    pub fn values<'a>(&'a self, day: u8, params: &'a [Param]) -> Values<'a> {
+        Values {
+            day,
+            params,
+            set: self.days.get(&day),
+        }
+    }
+}
+
+fn check_value(day: u8, param: &Param, value: &Value) -> Result<(), String> {
+    let default = param.default_value();
+    if !same_type(value, &default) {
+        return Err(format!(
+            "The param {} of day {} is {} like {}, not {}",
+            param.name,
+            day,
+            default.type_str(),
+            param.default,
+            value
+        ));
+    }
+    (param.check)(value).map_err(|expected| {
+        format!(
+            "The param {} of day {} must be {}, not {}",
+            param.name, day, expected, value
+        )
+    })
+}
+
+/// The values of the params of a day, see [`Config::values`].
+pub struct Values<'a> {
+    day: u8,
+    params: &'a [Param],
+    set: Option<&'a Table>,
+}
+
+impl Values<'_> {
+    /// The value of a param, as the type the solver reads it as.
+    pub fn get<T: DeserializeOwned>(&self, name: &str) -> Result<T, String> {
+        let param = self
+            .params
+            .iter()
+            .find(|p| p.name == name)
+            .ok_or_else(|| format!("Day {} declares no param {}", self.day, name))?;
+        let value = match self.set.and_then(|values| values.get(name)) {
+            Some(value) => value.clone(),
+            None => param.default_value(),
+        };
+        check_value(self.day, param, &value)?;
+        value.try_into().map_err(|e| {
+            format!(
+                "The param {} of day {} is not a {}, {}",
+                name,
+                self.day,
+                std::any::type_name::<T>(),
+                e
+            )
+        })
+    }
+
+    /// Check that the param `min` is not above the param `max`.
+    pub fn check_order(&self, min: &str, max: &str) -> Result<(), String> {
+        let (low, high) = (self.get::<usize>(min)?, self.get::<usize>(max)?);
+        if low > high {
+            return Err(format!(
+                "The param {} of day {} must not be above {}, {} > {}",
+                min, self.day, max, low, high
+            ));
+        }
+        Ok(())
+    }
+}
+
+static CONFIG: RwLock<Config> = RwLock::new(Config {
+    days: BTreeMap::new(),
+});
+
+thread_local! {
+    static SCOPED: RefCell<Option<Config>> = const { RefCell::new(None) };
+}
+
+/// Use these values from now on, for every thread. They must have been
+/// checked.
+pub fn set(config: Config) {
+    *CONFIG.write().unwrap() = config;
+}
+
+/// Run `f` with these values instead of those [`set`], on this thread only,
+/// so that tests running side by side don't see each other's. They must have
+/// been checked.
+pub fn with<R>(config: Config, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Config>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED.with(|scoped| *scoped.borrow_mut() = self.0.take());
+        }
+    }
+    let _restore = Restore(SCOPED.with(|scoped| scoped.replace(Some(config))));
+    f()
+}
+
+/// Read the values in use on this thread.
+fn current<R>(f: impl FnOnce(&Config) -> R) -> R {
+    SCOPED.with(|scoped| match &*scoped.borrow() {
+        Some(config) => f(config),
+        None => f(&CONFIG.read().unwrap()),
+    })
+}
+
+/// The values set for a day, the cache tells its answers apart with them.
+pub fn overrides(day: u8) -> Table {
+    current(|config| config.day(day))
+}
+
+/// The value of a param of `S`, its default unless it was set. Fails on a
+/// param `S` does not declare or a value that was not checked.
+pub fn get<S: Solution, T: DeserializeOwned>(name: &str) -> Result<T, String> {
+    current(|config| config.values(S::DAY, S::PARAMS).get(name))
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    use crate::parse::ParseError;
+
+    struct Tuned;
+
+    impl Solution for Tuned {
+        const DAY: u8 = 99;
+        const PARAMS: &'static [Param] = &[
+            Param {
+                name: "steps",
+                default: "64",
+                doc: "",
+                check: at_least::<1>,
+            },
+            Param {
+                name: "max_steps",
+                default: "100",
+                doc: "",
+                check: at_least::<0>,
+            },
+            Param {
+                name: "names",
+                default: r#"["sh", "mz"]"#,
+                doc: "",
+                check: any,
+            },
+        ];
+
+        type Model = ();
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(_input: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn check_params(params: &Values) -> Result<(), String> {
+            params.check_order("steps", "max_steps")
+        }
+
+        fn part1(_model: &()) -> usize {
+            get::<Self, usize>("steps").unwrap()
+        }
+
+        fn part2(_model: &()) -> usize {
+            get::<Self, Vec<String>>("names").unwrap().len()
+        }
+    }
+
+    fn check(config: &Config) -> Result<(), String> {
+        config.check(99, Tuned::PARAMS, Tuned::check_params)
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(parse_value("100"), Ok(Value::Integer(100)));
+        assert_eq!(parse_value("rx"), Ok(Value::String("rx".to_string())));
+        assert_eq!(parse_value("\"a b\""), Ok(Value::String("a b".to_string())));
+        assert_eq!(
+            parse_value("[1, 2]"),
+            Ok(Value::Array(vec![Value::Integer(1), Value::Integer(2)]))
+        );
+        assert!(parse_value("a b").is_err());
+        assert!(parse_value("1\nother = 2").is_err());
+    }
+
+    #[test]
+    fn test_checks() {
+        assert_eq!(at_least::<1>(&Value::Integer(1)), Ok(()));
+        assert_eq!(
+            at_least::<1>(&Value::Integer(0)),
+            Err("an integer from 1".to_string())
+        );
+        assert!(at_least::<0>(&Value::Integer(-1)).is_err());
+        assert!(at_least::<0>(&Value::String("1".to_string())).is_err());
+        assert_eq!(non_empty(&parse_value("[1]").unwrap()), Ok(()));
+        assert!(non_empty(&parse_value("[]").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let no_check = |_: &Values| Ok(());
+        let mut config = Config::parse("[day_99]\nsteps = 6\nnames = [\"a\"]\n").unwrap();
+        assert_eq!(check(&config), Ok(()));
+        config.set(99, "steps", "many").unwrap();
+        assert_eq!(
+            check(&config),
+            Err("The param steps of day 99 is integer like 64, not \"many\"".to_string())
+        );
+        config.set(99, "steps", "0").unwrap();
+        assert_eq!(
+            check(&config),
+            Err("The param steps of day 99 must be an integer from 1, not 0".to_string())
+        );
+        config.set(99, "steps", "-3").unwrap();
+        assert!(check(&config).is_err());
+        config.set(99, "steps", "101").unwrap();
+        assert_eq!(
+            check(&config),
+            Err("The param steps of day 99 must not be above max_steps, 101 > 100".to_string())
+        );
+        config.set(99, "max_steps", "200").unwrap();
+        assert_eq!(check(&config), Ok(()));
+        config.set(99, "names", "[1]").unwrap();
+        assert!(check(&config).is_err());
+        config.set(99, "names", "[]").unwrap();
+        config.set(99, "expansion", "10").unwrap();
+        assert_eq!(
+            check(&config),
+            Err("Day 99 has no param expansion, only steps, max_steps, names".to_string())
+        );
+        assert!(config.check(99, &[], no_check).is_err());
+        assert_eq!(config.check(1, &[], no_check), Ok(()));
+
+        assert!(Config::parse("[day_xx]\nsteps = 6").is_err());
+        assert!(Config::parse("steps = 6").is_err());
+        assert!(Config::parse("[day_99\n").is_err());
+    }
+
+    // The only test setting the values, they are shared by the whole process
+    #[test]
+    fn test_get() {
+        assert_eq!(Tuned::part1(&()), 64);
+        assert_eq!(Tuned::part2(&()), 2);
+
+        let mut config = Config::default();
+        config.set(99, "steps", "6").unwrap();
+        set(config);
+        assert_eq!(Tuned::part1(&()), 6);
+        assert_eq!(Tuned::part2(&()), 2);
+        assert_eq!(overrides(99).to_string(), "steps = 6\n");
+        assert_eq!(overrides(1), Table::new());
+
+        // Unchecked values fail to be read instead of reaching the solver
+        let mut config = Config::default();
+        config.set(99, "steps", "-1").unwrap();
+        set(config);
+        assert_eq!(
+            get::<Tuned, usize>("steps"),
+            Err("The param steps of day 99 must be an integer from 1, not -1".to_string())
+        );
+        assert!(get::<Tuned, String>("names").is_err());
+        assert_eq!(
+            get::<Tuned, usize>("expansion"),
+            Err("Day 99 declares no param expansion".to_string())
+        );
+
+        set(Config::default());
+        assert_eq!(Tuned::part1(&()), 64);
+    }
+
+    #[test]
+    fn test_with() {
+        // test_get sets the steps for every thread, only the names are safe
+        // to compare here
+        let mut config = Config::default();
+        config.set(99, "names", "[\"a\"]").unwrap();
+        assert_eq!(with(config.clone(), || Tuned::part2(&())), 1);
+        assert_eq!(Tuned::part2(&()), 2);
+        // Only on this thread
+        let other = with(config.clone(), || {
+            std::thread::spawn(|| Tuned::part2(&())).join().unwrap()
+        });
+        assert_eq!(other, 2);
+
+        let panicked = std::panic::catch_unwind(|| with(config, || panic!("in the solver")));
+        assert!(panicked.is_err());
+        assert_eq!(Tuned::part2(&()), 2);
+    }
+}
@@ -0,0 +1,250 @@
+use std::{borrow::Cow, fmt};
+
+use nom::error::{ErrorKind, VerboseError, VerboseErrorKind};
+
+/// The nom result type used by the day parsers, with errors that keep track of
+/// where they happened and what the parser was looking for.
+pub type IResult<I, O> = nom::IResult<I, O, VerboseError<I>>;
+
+/// A parse failure pointing at the offending line of the puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The whole offending line.
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Error at the start of `remaining`, which must be a suffix of `input`.
+    pub fn at(input: &str, remaining: &str, expected: impl Into<String>) -> Self {
+        let offset = input.len() - remaining.len();
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let text = input[line_start..].lines().next().unwrap_or("");
+
+        ParseError::new(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            text.trim_end_matches('\r'),
+            expected,
+        )
+    }
+
+    pub fn from_verbose(input: &str, error: VerboseError<&str>) -> Self {
+        let Some((remaining, kind)) = error.errors.first() else {
+            return ParseError::at(input, input, "valid input");
+        };
+        // The innermost error gives the position, but the closest context says
+        // more about what was expected than a bare char or nom error kind.
+        let context = error.errors.iter().find_map(|(_, kind)| match kind {
+            VerboseErrorKind::Context(context) => Some(context.to_string()),
+            _ => None,
+        });
+        let expected = context.unwrap_or_else(|| match kind {
+            VerboseErrorKind::Char(c) => format!("{:?}", c),
+            VerboseErrorKind::Context(context) => context.to_string(),
+            VerboseErrorKind::Nom(kind) => describe(*kind),
+        });
+
+        ParseError::at(input, remaining, expected)
+    }
+}
+
+fn describe(kind: ErrorKind) -> String {
+    let description = match kind {
+        ErrorKind::Digit => "a number",
+        ErrorKind::Alpha => "a letter",
+        ErrorKind::AlphaNumeric => "a letter or a digit",
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+        ErrorKind::CrLf => "a line ending",
+        ErrorKind::Eof => "end of input",
+        ErrorKind::OneOf => "one of the allowed characters",
+        ErrorKind::Many1 | ErrorKind::SeparatedList => "at least one item",
+        ErrorKind::TakeUntil => "a separator",
+        _ => return kind.description().to_lowercase(),
+    };
+    description.to_string()
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "{} |", margin)?;
+        writeln!(f, "{} | {}", number, self.text)?;
+        write!(f, "{} | {}^", margin, " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A failure at the start of `at` expecting `context`, for the checks a
+/// parser makes on what it parsed: a name that is defined, a tile that is
+/// there once...
+pub fn failure<'a>(at: &'a str, context: &'static str) -> nom::Err<VerboseError<&'a str>> {
+    nom::Err::Failure(VerboseError {
+        errors: vec![(at, VerboseErrorKind::Context(context))],
+    })
+}
+
+/// Run `parser` over the whole input. Anything but whitespace left over once
+/// it is done is an error, rather than being silently dropped.
+pub fn finish<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O, ParseError> {
+    match parser(input) {
+        Ok((remaining, output)) => {
+            let trimmed = remaining.trim_start();
+            if trimmed.is_empty() {
+                Ok(output)
+            } else {
+                Err(ParseError::at(input, trimmed, "end of input"))
+            }
+        }
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseError::from_verbose(input, e)),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(input, "", "more input")),
+    }
+}
+
+/// Bring a puzzle input to the shape every parser expects: no UTF-8 BOM, LF
+/// line endings, and a final newline unless the input is empty.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let stripped = input.strip_prefix('\u{feff}').unwrap_or(input);
+    if !stripped.contains('\r') && (stripped.is_empty() || stripped.ends_with('\n')) {
+        return Cow::Borrowed(stripped);
+    }
+    let mut normalized = stripped.replace("\r\n", "\n");
+    if !normalized.is_empty() && !normalized.ends_with('\n') {
+        normalized.push('\n');
+    }
+    Cow::Owned(normalized)
+}
+
+/// The lines of a normalized puzzle input, for the days parsed line by line.
+pub fn lines(input: &str) -> Vec<String> {
+    normalize(input).lines().map(str::to_string).collect()
+}
+
+/// [`finish`] on the normalized input. Every `Solution::parse` goes through
+/// this or [`lines`], so the parsers only ever see LF terminated lines.
+pub fn parse_input<O>(
+    input: &str,
+    parser: impl for<'a> FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O, ParseError> {
+    finish(&normalize(input), parser)
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{char, line_ending, u32},
+        error::context,
+        multi::separated_list1,
+        sequence::{preceded, terminated},
+    };
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    fn parse_line(input: &str) -> IResult<&str, u32> {
+        preceded(tag("Game "), terminated(u32, char(':')))(input)
+    }
+
+    fn parse(input: &str) -> IResult<&str, Vec<u32>> {
+        separated_list1(line_ending, parse_line)(input)
+    }
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_at() {
+        let input = "abc\ndéf\n";
+        assert_eq!(
+            ParseError::at(input, &input[7..], "x"),
+            ParseError::new(2, 3, "déf", "x")
+        );
+        assert_eq!(
+            ParseError::at(input, "", "x"),
+            ParseError::new(3, 1, "", "x")
+        );
+    }
+
+    #[test]
+    fn test_finish() {
+        assert_eq!(finish("Game 1:\nGame 2:\n", parse), Ok(vec![1, 2]));
+        assert_eq!(
+            finish("Game 1:\nGame 2;\n", parse),
+            Err(ParseError::new(2, 1, "Game 2;", "end of input"))
+        );
+        assert_eq!(finish("Game 1:\n\n  \n", parse_line), Ok(1));
+        assert_eq!(
+            finish("Game x:\n", parse),
+            Err(ParseError::new(1, 6, "Game x:", "a number"))
+        );
+        assert_eq!(
+            finish("Game 1;\n", parse),
+            Err(ParseError::new(1, 7, "Game 1;", "':'"))
+        );
+        assert_eq!(
+            finish("Game x:\n", context("a game number", parse)),
+            Err(ParseError::new(1, 6, "Game x:", "a game number"))
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("a\nb\n"), Cow::Borrowed("a\nb\n"));
+        assert_eq!(normalize("a\r\nb\r\n"), "a\nb\n");
+        assert_eq!(normalize("a\nb"), "a\nb\n");
+        assert_eq!(normalize("\u{feff}a\r\nb"), "a\nb\n");
+        assert_eq!(normalize("\u{feff}"), "");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(lines("a\r\n\r\nb"), vec!["a", "", "b"]);
+        assert_eq!(lines("\u{feff}a\n"), vec!["a"]);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("Game 1:\r\nGame 2:", parse), Ok(vec![1, 2]));
+        assert_eq!(
+            parse_input("\u{feff}Game 1:\r\nGame 2;\r\n", parse),
+            Err(ParseError::new(2, 1, "Game 2;", "end of input"))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::new(12, 6, "Game x:", "a number");
+        assert_eq!(
+            error.to_string(),
+            "line 12, column 6: expected a number\n   |\n12 | Game x:\n   |      ^"
+        );
+    }
+}
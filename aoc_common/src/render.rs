@@ -0,0 +1,534 @@
+//! Pictures of grid states, for when a `TextMap` is too small to follow
+//! what a solver does. An [`Image`] is a grid coloured through a [`Palette`],
+//! with overlays on top (highlighted cells, a path, heat values), written as
+//! binary PPM or as SVG. [`Frames`] numbers a sequence of them to make an
+//! animation from, e.g. with
+//! `ffmpeg -i frames/day_14/frame_%05d.ppm day_14.gif`.
+//!
+//! Solvers write frames only when the `AOC_FRAMES` environment variable names
+//! a directory, see [`Frames::from_env`].
+
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{Coord, Grid};
+
+/// Directory the solvers write their frames to, one sub-directory per day.
+pub const ENV_VAR: &str = "AOC_FRAMES";
+/// Format of the frames, `ppm` (the default) or `svg`.
+pub const FORMAT_ENV_VAR: &str = "AOC_FRAMES_FORMAT";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(133, 153, 0);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb(181, 137, 0);
+
+    /// The colour `t` of the way from `self` to `other`, `t` being clamped
+    /// to `0.0..=1.0`.
+    pub fn lerp(self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// The colour of each character of a map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: HashMap<char, Rgb>,
+    default: Rgb,
+}
+
+impl Palette {
+    /// A palette with no colours yet, every character is drawn in `default`.
+    pub fn new(default: Rgb) -> Self {
+        Self {
+            colors: HashMap::new(),
+            default,
+        }
+    }
+
+    pub fn with(mut self, c: char, color: Rgb) -> Self {
+        self.colors.insert(c, color);
+        self
+    }
+
+    pub fn color(&self, c: char) -> Rgb {
+        self.colors.get(&c).copied().unwrap_or(self.default)
+    }
+}
+
+/// Colours for the characters most puzzles use: dark ground, grey walls,
+/// yellow rocks, a green start and a red end.
+impl Default for Palette {
+    fn default() -> Self {
+        Self::new(Rgb(147, 161, 161))
+            .with('.', Rgb(0, 43, 54))
+            .with('#', Rgb(88, 110, 117))
+            .with('O', Rgb::YELLOW)
+            .with('S', Rgb::GREEN)
+            .with('E', Rgb::RED)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Binary PPM (P6), one square of pixels per tile.
+    Ppm,
+    /// SVG, one rectangle per run of tiles of the same colour in a row.
+    Svg,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Svg => "svg",
+        }
+    }
+
+    /// The format a file name ends with.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "ppm" => Some(Format::Ppm),
+            "svg" => Some(Format::Svg),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Overlay {
+    Cells(Vec<Coord>, Rgb),
+    Path(Vec<Coord>, Rgb),
+}
+
+/// A grid state ready to be written as a picture.
+#[derive(Debug, Clone)]
+pub struct Image {
+    tiles: Grid<Rgb>,
+    overlays: Vec<Overlay>,
+    scale: usize,
+}
+
+impl Image {
+    /// The map coloured through `palette`.
+    pub fn new(grid: &Grid<char>, palette: &Palette) -> Self {
+        Self::from_colors(grid.map(|c| palette.color(*c)))
+    }
+
+    pub fn from_colors(tiles: Grid<Rgb>) -> Self {
+        Self {
+            tiles,
+            overlays: Vec::new(),
+            scale: 4,
+        }
+    }
+
+    /// Size of a tile in pixels, 4 by default.
+    pub fn scale(mut self, scale: usize) -> Self {
+        assert!(scale > 0, "a tile must be at least one pixel wide");
+        self.scale = scale;
+        self
+    }
+
+    /// Paint `cells` in `color`, over the map and the previous overlays.
+    /// Cells outside of the grid are ignored.
+    pub fn highlight(mut self, cells: impl IntoIterator<Item = Coord>, color: Rgb) -> Self {
+        self.overlays
+            .push(Overlay::Cells(cells.into_iter().collect(), color));
+        self
+    }
+
+    /// Draw a line through `points`, which go horizontally, vertically or
+    /// diagonally from one to the next.
+    ///
+    /// Panics on a segment at another angle, it has no tiles to go through.
+    #[track_caller]
+    pub fn path(mut self, points: impl IntoIterator<Item = Coord>, color: Rgb) -> Self {
+        let points = points.into_iter().collect::<Vec<_>>();
+        for pair in points.windows(2) {
+            let (dx, dy) = ((pair[1].x - pair[0].x).abs(), (pair[1].y - pair[0].y).abs());
+            assert!(
+                dx == 0 || dy == 0 || dx == dy,
+                "the path from ({}, {}) to ({}, {}) is neither straight nor diagonal",
+                pair[0].x,
+                pair[0].y,
+                pair[1].x,
+                pair[1].y
+            );
+        }
+        self.overlays.push(Overlay::Path(points, color));
+        self
+    }
+
+    /// Colour tiles by value, from blue for the smallest to red for the
+    /// largest. The tiles without a value keep their colour.
+    pub fn heat(mut self, values: impl IntoIterator<Item = (Coord, f64)>) -> Self {
+        let values = values.into_iter().collect::<Vec<_>>();
+        let min = values.iter().map(|v| v.1).fold(f64::INFINITY, f64::min);
+        let max = values.iter().map(|v| v.1).fold(f64::NEG_INFINITY, f64::max);
+        for (coord, value) in values {
+            let t = if max > min {
+                (value - min) / (max - min)
+            } else {
+                0.0
+            };
+            if let Some(tile) = self.tiles.get_mut(coord) {
+                *tile = Rgb::BLUE.lerp(Rgb::RED, t);
+            }
+        }
+        self
+    }
+
+    /// The colour of every tile once the overlays are painted on it.
+    pub fn tiles(&self) -> Grid<Rgb> {
+        let mut tiles = self.tiles.clone();
+        for overlay in &self.overlays {
+            let (cells, color) = match overlay {
+                Overlay::Cells(cells, color) => (cells.clone(), *color),
+                Overlay::Path(points, color) => (path_cells(points), *color),
+            };
+            for cell in cells {
+                if let Some(tile) = tiles.get_mut(cell) {
+                    *tile = color;
+                }
+            }
+        }
+        tiles
+    }
+
+    pub fn write(&self, out: &mut impl Write, format: Format) -> io::Result<()> {
+        match format {
+            Format::Ppm => self.write_ppm(out),
+            Format::Svg => self.write_svg(out),
+        }
+    }
+
+    pub fn write_ppm(&self, out: &mut impl Write) -> io::Result<()> {
+        let tiles = self.tiles();
+        writeln!(
+            out,
+            "P6\n{} {}\n255",
+            tiles.width() * self.scale,
+            tiles.height() * self.scale
+        )?;
+        for row in tiles.rows() {
+            let line = row
+                .iter()
+                .flat_map(|c| [c.0, c.1, c.2].repeat(self.scale))
+                .collect::<Vec<u8>>();
+            for _ in 0..self.scale {
+                out.write_all(&line)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Paths stay vector lines through the middle of the tiles, the rest is
+    /// painted on the tiles like for PPM.
+    pub fn write_svg(&self, out: &mut impl Write) -> io::Result<()> {
+        let mut tiles = self.tiles.clone();
+        for overlay in &self.overlays {
+            if let Overlay::Cells(cells, color) = overlay {
+                for cell in cells {
+                    if let Some(tile) = tiles.get_mut(*cell) {
+                        *tile = *color;
+                    }
+                }
+            }
+        }
+
+        let s = self.scale;
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" shape-rendering="crispEdges">"#,
+            tiles.width() * s,
+            tiles.height() * s
+        )?;
+        for (y, row) in tiles.rows().enumerate() {
+            let mut x = 0;
+            for run in row.chunk_by(|a, b| a == b) {
+                writeln!(
+                    out,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                    x * s,
+                    y * s,
+                    run.len() * s,
+                    s,
+                    run[0].hex()
+                )?;
+                x += run.len();
+            }
+        }
+        for overlay in &self.overlays {
+            if let Overlay::Path(points, color) = overlay {
+                let points = points
+                    .iter()
+                    .map(|p| {
+                        format!(
+                            "{},{}",
+                            p.x as f64 * s as f64 + s as f64 / 2.0,
+                            p.y as f64 * s as f64 + s as f64 / 2.0
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                writeln!(
+                    out,
+                    r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linejoin="round"/>"#,
+                    points,
+                    color.hex(),
+                    (s as f64 / 3.0).max(1.0)
+                )?;
+            }
+        }
+        writeln!(out, "</svg>")
+    }
+
+    /// Write the image to `path`, in the format its extension names.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let format = Format::from_path(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is neither a .ppm nor a .svg file", path.display()),
+            )
+        })?;
+        let mut out = BufWriter::new(fs::File::create(path)?);
+        self.write(&mut out, format)?;
+        out.flush()
+    }
+}
+
+/// Every tile the path goes through, corners included once.
+fn path_cells(points: &[Coord]) -> Vec<Coord> {
+    let mut cells = points.first().copied().into_iter().collect::<Vec<_>>();
+    for pair in points.windows(2) {
+        let (mut c, to) = (pair[0], pair[1]);
+        let step = Coord::new((to.x - c.x).signum(), (to.y - c.y).signum());
+        while c != to {
+            c += step;
+            cells.push(c);
+        }
+    }
+    cells
+}
+
+/// Numbered images written to a directory, `frame_00000.ppm` and on.
+/// Frames left there by a previous run are overwritten, not removed.
+#[derive(Debug)]
+pub struct Frames {
+    dir: PathBuf,
+    format: Format,
+    count: usize,
+}
+
+impl Frames {
+    /// Write frames to `dir`, creating it when needed.
+    pub fn new(dir: impl Into<PathBuf>, format: Format) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            format,
+            count: 0,
+        })
+    }
+
+    /// Frames in `$AOC_FRAMES/<name>`, `None` when the variable is not set so
+    /// the solvers don't even build the images. A directory that cannot be
+    /// created is logged and treated the same way.
+    pub fn from_env(name: &str) -> Option<Self> {
+        let root = std::env::var_os(ENV_VAR)?;
+        let format = match std::env::var(FORMAT_ENV_VAR).as_deref() {
+            Ok("svg") => Format::Svg,
+            Ok("ppm") | Err(_) => Format::Ppm,
+            Ok(other) => {
+                log::warn!("unknown {} {:?}, writing PPM", FORMAT_ENV_VAR, other);
+                Format::Ppm
+            }
+        };
+        let dir = Path::new(&root).join(name);
+        Self::new(&dir, format)
+            .inspect_err(|e| log::warn!("cannot write frames to {}: {}", dir.display(), e))
+            .ok()
+    }
+
+    /// Write the next frame and return its path.
+    pub fn push(&mut self, image: &Image) -> io::Result<PathBuf> {
+        let path = self.dir.join(format!(
+            "frame_{:05}.{}",
+            self.count,
+            self.format.extension()
+        ));
+        image.save(&path)?;
+        self.count += 1;
+        Ok(path)
+    }
+
+    /// [`Frames::push`] for solvers, which log a frame that cannot be written
+    /// instead of failing.
+    pub fn record(&mut self, image: &Image) {
+        if let Err(e) = self.push(image) {
+            log::warn!("cannot write frame {}: {}", self.count, e);
+        }
+    }
+
+    /// Number of frames written so far.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn image() -> Image {
+        let grid = Grid::parse("#.\n.O\n").unwrap();
+        let palette = Palette::new(Rgb::WHITE)
+            .with('#', Rgb(1, 2, 3))
+            .with('.', Rgb::BLACK);
+        Image::new(&grid, &palette).scale(1)
+    }
+
+    #[test]
+    fn test_palette() {
+        let palette = Palette::default();
+        assert_eq!(palette.color('O'), Rgb::YELLOW);
+        assert_eq!(palette.color('?'), palette.color('~'));
+        assert_eq!(Rgb::BLACK.lerp(Rgb::WHITE, 0.5), Rgb(128, 128, 128));
+        assert_eq!(Rgb::BLACK.lerp(Rgb::WHITE, 7.0), Rgb::WHITE);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let mut out = Vec::new();
+        image().write_ppm(&mut out).unwrap();
+        let mut expected = b"P6\n2 2\n255\n".to_vec();
+        expected.extend([1, 2, 3, 0, 0, 0, 0, 0, 0, 255, 255, 255]);
+        assert_eq!(out, expected);
+
+        // Every tile becomes a square of pixels
+        let mut out = Vec::new();
+        image().scale(3).write_ppm(&mut out).unwrap();
+        assert!(out.starts_with(b"P6\n6 6\n255\n"));
+        assert_eq!(out.len(), b"P6\n6 6\n255\n".len() + 6 * 6 * 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "the path from (0, 0) to (3, 1) is neither straight nor diagonal")]
+    fn test_skewed_path() {
+        let _ = image().path([Coord::new(0, 0), Coord::new(3, 1)], Rgb::RED);
+    }
+
+    #[test]
+    fn test_overlays() {
+        let tiles = image()
+            .highlight([Coord::new(1, 1), Coord::new(5, 5)], Rgb::GREEN)
+            .tiles();
+        assert_eq!(tiles[Coord::new(1, 1)], Rgb::GREEN);
+        assert_eq!(tiles[Coord::new(0, 0)], Rgb(1, 2, 3));
+
+        let tiles = image()
+            .path(
+                [Coord::new(0, 0), Coord::new(1, 0), Coord::new(1, 1)],
+                Rgb::RED,
+            )
+            .tiles();
+        assert_eq!(tiles.iter().filter(|(_, c)| **c == Rgb::RED).count(), 3);
+        assert_eq!(
+            path_cells(&[Coord::new(0, 0), Coord::new(3, 0), Coord::new(1, 2)]),
+            vec![
+                Coord::new(0, 0),
+                Coord::new(1, 0),
+                Coord::new(2, 0),
+                Coord::new(3, 0),
+                Coord::new(2, 1),
+                Coord::new(1, 2),
+            ]
+        );
+
+        let tiles = image()
+            .heat([
+                (Coord::new(0, 0), 2.0),
+                (Coord::new(1, 0), 4.0),
+                (Coord::new(0, 1), 3.0),
+            ])
+            .tiles();
+        assert_eq!(tiles[Coord::new(0, 0)], Rgb::BLUE);
+        assert_eq!(tiles[Coord::new(1, 0)], Rgb::RED);
+        assert_eq!(tiles[Coord::new(0, 1)], Rgb::BLUE.lerp(Rgb::RED, 0.5));
+        assert_eq!(tiles[Coord::new(1, 1)], Rgb::WHITE);
+    }
+
+    #[test]
+    fn test_svg() {
+        let mut out = Vec::new();
+        image()
+            .scale(10)
+            .path([Coord::new(0, 0), Coord::new(1, 1)], Rgb::RED)
+            .write_svg(&mut out)
+            .unwrap();
+        let svg = String::from_utf8(out).unwrap();
+        assert!(
+            svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="20""#)
+        );
+        assert!(svg.contains(r##"<rect x="0" y="0" width="10" height="10" fill="#010203"/>"##));
+        assert!(svg.contains(r##"<rect x="10" y="0" width="10" height="10" fill="#000000"/>"##));
+        assert!(svg.contains(r#"points="5,5 15,15""#));
+        assert!(svg.ends_with("</svg>\n"));
+
+        // Tiles of the same colour in a row make one rectangle
+        let mut out = Vec::new();
+        Image::from_colors(Grid::new(3, 1, Rgb::BLACK))
+            .write_svg(&mut out)
+            .unwrap();
+        let svg = String::from_utf8(out).unwrap();
+        assert_eq!(svg.matches("<rect").count(), 1);
+        assert!(svg.contains(r#"width="12" height="4""#));
+    }
+
+    #[test]
+    fn test_frames() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut frames = Frames::new(dir.path().join("day_00"), Format::Svg).unwrap();
+        assert!(frames.is_empty());
+        let first = frames.push(&image()).unwrap();
+        frames.record(&image());
+        assert_eq!(frames.len(), 2);
+        assert_eq!(first, dir.path().join("day_00/frame_00000.svg"));
+        assert!(dir.path().join("day_00/frame_00001.svg").is_file());
+
+        assert!(image().save(dir.path().join("image.png")).is_err());
+        image().save(dir.path().join("image.ppm")).unwrap();
+        assert_eq!(
+            fs::read(dir.path().join("image.ppm")).unwrap().len(),
+            b"P6\n2 2\n255\n".len() + 12
+        );
+    }
+}
@@ -0,0 +1,257 @@
+//! Searches over implicit graphs: the states are discovered through a
+//! successor function as the search goes, so the graph never has to be built
+//! up front.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// How much work a search did.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// States whose successors were generated.
+    pub explored: usize,
+    /// States reached, explored or not.
+    pub discovered: usize,
+}
+
+/// The outcome of a search: the distance to every state reached and the
+/// state each one was reached from.
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    pub distances: HashMap<S, usize>,
+    pub predecessors: HashMap<S, S>,
+    /// The first goal state taken out of the queue, the search stops there.
+    pub goal: Option<S>,
+    pub stats: Stats,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+            stats: Stats::default(),
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    /// Distance to the goal, `None` when no goal was reached.
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+
+    /// The states from a start to `to`, both included. Empty when `to` was
+    /// never reached.
+    pub fn path(&self, to: &S) -> Vec<S> {
+        if !self.distances.contains_key(to) {
+            return Vec::new();
+        }
+        let mut path = vec![to.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth-first search, every move costs 1. Stops at the first state for
+/// which `goal` is true, pass `|_| false` to reach everything.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.distances.contains_key(&start) {
+            search.distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        search.stats.explored += 1;
+        let distance = search.distances[&state] + 1;
+        for next in successors(&state) {
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), distance);
+                search.predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    search.stats.discovered = search.distances.len();
+    search
+}
+
+/// Dijkstra's shortest paths, `successors` gives the next states with the
+/// cost of moving to them. Stops at the first goal like [`bfs`].
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(starts, successors, |_| 0, goal)
+}
+
+/// A* search: [`dijkstra`] guided by `heuristic`, which must never
+/// overestimate the remaining cost to a goal for the result to be the
+/// shortest.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut search = Search::new();
+    // The heap orders (estimate, distance, index in `states`) so the states
+    // themselves don't have to be `Ord`.
+    let mut states = Vec::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if !search.distances.contains_key(&start) {
+            search.distances.insert(start.clone(), 0);
+            queue.push(Reverse((heuristic(&start), 0, states.len())));
+            states.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, distance, index))) = queue.pop() {
+        let state = states[index].clone();
+        if search.distances[&state] < distance {
+            // Reached again for cheaper since it was queued
+            continue;
+        }
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        search.stats.explored += 1;
+        for (next, cost) in successors(&state) {
+            let next_distance = distance + cost;
+            if search
+                .distances
+                .get(&next)
+                .is_none_or(|known| next_distance < *known)
+            {
+                search.distances.insert(next.clone(), next_distance);
+                search.predecessors.insert(next.clone(), state.clone());
+                queue.push(Reverse((
+                    next_distance + heuristic(&next),
+                    next_distance,
+                    states.len(),
+                )));
+                states.push(next);
+            }
+        }
+    }
+    search.stats.discovered = search.distances.len();
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Coord, Grid};
+    use pretty_assertions::assert_eq;
+
+    fn maze() -> Grid<char> {
+        Grid::parse("S.#.\n.##.\n...E\n").unwrap()
+    }
+
+    fn open_neighbours(grid: &Grid<char>, coord: &Coord) -> Vec<Coord> {
+        grid.neighbours(*coord)
+            .filter(|(_, c)| **c != '#')
+            .map(|(c, _)| c)
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let start = grid.position(|c| *c == 'S').unwrap();
+        let end = grid.position(|c| *c == 'E').unwrap();
+
+        let search = bfs([start], |c| open_neighbours(&grid, c), |c| *c == end);
+        assert_eq!(search.goal, Some(end));
+        assert_eq!(search.goal_distance(), Some(5));
+        let path = search.path(&end);
+        assert_eq!(path.len(), 6);
+        assert_eq!(path.first(), Some(&start));
+        assert!(path.windows(2).all(|w| w[0].manhattan(&w[1]) == 1));
+
+        let everything = bfs([start], |c| open_neighbours(&grid, c), |_| false);
+        assert_eq!(everything.goal, None);
+        assert_eq!(everything.stats.discovered, 9);
+        assert_eq!(everything.stats.explored, 9);
+        assert_eq!(everything.distance(&Coord::new(3, 0)), Some(7));
+        assert_eq!(everything.path(&Coord::new(2, 0)), vec![]);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // Going through 'b' is shorter than the direct edge
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('c', 5)]),
+            ('b', vec![('c', 2)]),
+            ('c', vec![('d', 1)]),
+        ]);
+        let next = |s: &char| edges.get(s).cloned().unwrap_or_default();
+
+        let search = dijkstra(['a'], next, |s| *s == 'd');
+        assert_eq!(search.goal_distance(), Some(4));
+        assert_eq!(search.path(&'d'), vec!['a', 'b', 'c', 'd']);
+
+        let search = dijkstra(['a', 'c'], next, |_| false);
+        assert_eq!(search.distance(&'d'), Some(1));
+        assert_eq!(search.path(&'d'), vec!['c', 'd']);
+    }
+
+    #[test]
+    fn test_astar_explores_less() {
+        let grid = Grid::new(20, 20, '.');
+        let end = Coord::new(19, 0);
+        let next = |c: &Coord| {
+            open_neighbours(&grid, c)
+                .into_iter()
+                .map(|n| (n, 1))
+                .collect::<Vec<_>>()
+        };
+
+        let blind = dijkstra([Coord::new(0, 0)], next, |c| *c == end);
+        let guided = astar(
+            [Coord::new(0, 0)],
+            next,
+            |c| c.manhattan(&end),
+            |c| *c == end,
+        );
+        assert_eq!(blind.goal_distance(), Some(19));
+        assert_eq!(guided.goal_distance(), Some(19));
+        assert!(guided.stats.explored < blind.stats.explored);
+    }
+}
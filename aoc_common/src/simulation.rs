@@ -0,0 +1,47 @@
+//! Puzzles that play out tick by tick, exposed so `aoc view` can step through
+//! them in the terminal instead of `dbg!`-ing every tick.
+
+use crate::{render::Rgb, Coord, Grid, Solution};
+
+/// A simulation the viewer drives. Its state is drawn as a character map,
+/// which does not have to be the puzzle's map: day 20 draws one module per
+/// line.
+pub trait Simulation: SimulationClone {
+    /// One line about the current state: the tick, counters, the load...
+    fn status(&self) -> String;
+    /// Move one tick forward. Returns false, leaving the state as it is, once
+    /// there is nothing left to do.
+    fn step(&mut self) -> bool;
+    fn map(&self) -> Grid<char>;
+    /// Tiles to paint on top of the map, e.g. the beams or the energized
+    /// tiles.
+    fn highlights(&self) -> Vec<(Coord, Rgb)> {
+        Vec::new()
+    }
+    /// What to say about the tile at `coord`, or about what is drawn there.
+    fn inspect(&self, coord: Coord) -> Option<String>;
+}
+
+/// Lets the viewer keep copies of a `Box<dyn Simulation>` to step back to.
+/// Implemented for every `Clone` simulation.
+pub trait SimulationClone {
+    fn clone_box(&self) -> Box<dyn Simulation>;
+}
+
+impl<T: Simulation + Clone + 'static> SimulationClone for T {
+    fn clone_box(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Simulation> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// A day whose puzzle can be watched as a simulation.
+pub trait Simulate: Solution {
+    /// The simulation at its first tick, from the parsed input.
+    fn simulation(model: &Self::Model) -> Box<dyn Simulation>;
+}
@@ -0,0 +1,87 @@
+//! Expected text kept in `day_XX/snapshots/<name>.txt`, for the tests of what
+//! rendered maps look like. `AOC_UPDATE_SNAPSHOTS=1 cargo test` writes the
+//! snapshots instead of comparing with them, check their diff before
+//! committing them.
+
+use std::{env, fs, path::Path};
+
+pub const UPDATE: &str = "AOC_UPDATE_SNAPSHOTS";
+
+/// Compare `actual` with the snapshot at `path`, or write it there when
+/// updating. Use [`assert_snapshot!`](crate::assert_snapshot) in the tests.
+#[track_caller]
+pub fn check(path: &Path, actual: &str) {
+    if env::var_os(UPDATE).is_some() {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .unwrap_or_else(|e| panic!("cannot create {}: {}", dir.display(), e));
+        }
+        fs::write(path, actual)
+            .unwrap_or_else(|e| panic!("cannot write the snapshot {}: {}", path.display(), e));
+        return;
+    }
+    let expected = fs::read_to_string(path).unwrap_or_else(|e| {
+        panic!(
+            "cannot read the snapshot {}: {}, run the test with {}=1 to write it",
+            path.display(),
+            e,
+            UPDATE
+        )
+    });
+    // Checked out with CRLF line endings on Windows
+    let expected = expected.replace("\r\n", "\n");
+    if expected == actual {
+        return;
+    }
+    let line = expected
+        .lines()
+        .zip(actual.lines())
+        .position(|(e, a)| e != a)
+        .unwrap_or(expected.lines().count().min(actual.lines().count()));
+    panic!(
+        "{} differs from line {}\nexpected:\n{}\nactual:\n{}",
+        path.display(),
+        line + 1,
+        expected,
+        actual
+    );
+}
+
+/// Compare the `Display` of a value with `snapshots/<name>.txt` in the crate
+/// of the test.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $actual:expr) => {
+        $crate::snapshot::check(
+            &::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("snapshots")
+                .join(format!("{}.txt", $name)),
+            &$actual.to_string(),
+        )
+    };
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_check() {
+        // Nothing is compared while updating
+        if env::var_os(UPDATE).is_some() {
+            return;
+        }
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("map.txt");
+        fs::write(&path, "#.\r\n.#\r\n").unwrap();
+        check(&path, "#.\n.#\n");
+
+        let message = std::panic::catch_unwind(|| check(&path, "#.\n##\n")).unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+        assert!(message.ends_with("differs from line 2\nexpected:\n#.\n.#\n\nactual:\n#.\n##\n"));
+        assert!(std::panic::catch_unwind(|| check(&path, "#.\n.#\n..\n")).is_err());
+        assert!(std::panic::catch_unwind(|| check(&dir.path().join("none.txt"), "")).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+use std::{
+    fmt::{self, Display},
+    process::ExitCode,
+};
+
+use crate::{
+    params::{Param, Values},
+    parse::ParseError,
+};
+
+/// The solution of one day: the input is parsed once into a model, and both
+/// parts are solved from it.
+pub trait Solution {
+    const DAY: u8;
+    /// Bump it when a fix changes the answers, the answers cached by `aoc run`
+    /// for the previous versions are not used anymore.
+    const VERSION: u32 = 1;
+    /// The values the day can be tuned with, read with [`crate::params::get`].
+    const PARAMS: &'static [Param] = &[];
+
+    /// Check the values of the params together, once each one passed its
+    /// [`Param::check`]: that a minimum is not above its maximum for instance.
+    fn check_params(_params: &Values) -> Result<(), String> {
+        Ok(())
+    }
+
+    type Model;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError>;
+    fn part1(model: &Self::Model) -> Self::Answer1;
+    fn part2(model: &Self::Model) -> Self::Answer2;
+}
+
+/// The answer of a part that some inputs have none for, like a goal that
+/// cannot be reached. Shown as `none` then, instead of panicking or looping
+/// forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Maybe<T>(pub Option<T>);
+
+impl<T: Display> Display for Maybe<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(answer) => answer.fmt(f),
+            None => write!(f, "none"),
+        }
+    }
+}
+
+/// Parse `input` and solve both parts, for crates that embed a day.
+pub fn solve<S: Solution>(input: &str) -> Result<(S::Answer1, S::Answer2), ParseError> {
+    let model = S::parse(input)?;
+    Ok((S::part1(&model), S::part2(&model)))
+}
+
+/// The `main` of the day crates: solve both parts of the default input.
+pub fn main<S: Solution>() -> ExitCode {
+    crate::logging::init(0);
+
+    let config = crate::params::Config::load(None);
+    match config.and_then(|config| {
+        config
+            .check(S::DAY, S::PARAMS, S::check_params)
+            .map(|_| config)
+    }) {
+        Ok(config) => crate::params::set(config),
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let input = match crate::input::Source::Default(S::DAY).read() {
+        Ok(input) => input,
+        // Without the hint about --input, which the day binaries don't take
+        Err(e) => {
+            eprintln!("cannot read puzzle input from {}: {}", e.source, e.error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match S::parse(&input) {
+        Ok(model) => {
+            println!("Part 1: {}", S::part1(&model));
+            println!("Part 2: {}", S::part2(&model));
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Invalid puzzle input, {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
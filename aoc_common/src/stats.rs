@@ -0,0 +1,62 @@
+//! Intermediate values a solver finds on its way to the answer (a loop
+//! length, a cycle, the numbers an lcm is taken of), for `aoc run --format
+//! json`. Solvers record them and the runner takes them once the solver is
+//! done. They are kept per thread, so solvers running side by side don't mix
+//! theirs up.
+
+use std::{cell::RefCell, collections::BTreeMap};
+
+use serde::Serialize;
+use serde_json::Value;
+
+thread_local! {
+    static STATS: RefCell<BTreeMap<String, Value>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Record `value` under `name`, replacing what was recorded under it before.
+pub fn record(name: &str, value: impl Serialize) {
+    let value = serde_json::to_value(value)
+        .unwrap_or_else(|e| panic!("cannot record the stat {}: {}", name, e));
+    STATS.with(|stats| stats.borrow_mut().insert(name.to_string(), value));
+}
+
+/// Everything recorded on this thread since the last call.
+pub fn take() -> BTreeMap<String, Value> {
+    STATS.with(|stats| std::mem::take(&mut *stats.borrow_mut()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn test_record_and_take() {
+        take();
+        record("loop_length", 140);
+        record("cycle", [3, 7]);
+        record("loop_length", 142);
+        assert_eq!(
+            take(),
+            BTreeMap::from([
+                ("cycle".to_string(), json!([3, 7])),
+                ("loop_length".to_string(), json!(142)),
+            ])
+        );
+        assert_eq!(take(), BTreeMap::new());
+    }
+
+    #[test]
+    fn test_per_thread() {
+        take();
+        record("here", 1);
+        std::thread::spawn(|| {
+            record("there", 2);
+            assert_eq!(take().len(), 1);
+        })
+        .join()
+        .unwrap();
+        assert_eq!(take(), BTreeMap::from([("here".to_string(), json!(1))]));
+    }
+}
@@ -0,0 +1,226 @@
+//! Day 1: Trebuchet?!
+//!
+//! The model is the calibration document, one line per entry. [`digits`] and
+//! [`calibration_value`] give the value of a single line.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+use aoc_common::{
+    parse::{lines, ParseError},
+    Solution,
+};
+
+const NUMBERS: [(&str, &str); 9] = [
+    ("one", "1"),
+    ("two", "2"),
+    ("three", "3"),
+    ("four", "4"),
+    ("five", "5"),
+    ("six", "6"),
+    ("seven", "7"),
+    ("eight", "8"),
+    ("nine", "9"),
+];
+
+fn join_digits(first_elem: u32, last_elem: u32) -> u32 {
+    format!("{}{}", first_elem, last_elem)
+        .parse::<u32>()
+        .unwrap()
+}
+
+/// The number made of the first and the last digit.
+pub fn calibration_value(mut digits: VecDeque<u32>) -> u32 {
+    if digits.len() == 1 {
+        let element = digits.pop_front().unwrap();
+        join_digits(element, element)
+    } else {
+        let first_elem = digits.pop_front().unwrap();
+        let last_elem = digits.pop_back().unwrap();
+        join_digits(first_elem, last_elem)
+    }
+}
+
+/// The digits of the line in order, the written out ones left aside.
+pub fn digits(line: &str) -> VecDeque<u32> {
+    line.chars()
+        .filter_map(|o| o.to_digit(10))
+        .collect::<VecDeque<u32>>()
+}
+
+fn replace_spelled_digits(o: &mut String, numbers: &HashMap<&str, &str>) {
+    let mut matches = Vec::new();
+    for key in numbers.keys() {
+        for i in o.match_indices(key) {
+            matches.push(i);
+        }
+    }
+    matches.sort();
+    log::trace!("matches = {:?}", matches);
+    let mut matches: VecDeque<(usize, &str)> = matches.into();
+    match matches.len() {
+        0 => {}
+        1 => {
+            let matches = matches.pop_front().unwrap().1;
+            *o = o.replace(matches, numbers[matches]);
+        }
+        _ => {
+            let (first_matches_index, first_matches) = matches.pop_front().unwrap();
+            let (last_matches_index, last_matches) = matches.pop_back().unwrap();
+
+            if first_matches_index + first_matches.len() <= last_matches_index {
+                // no overlap we can replace safely
+                *o = o
+                    .replace(first_matches, numbers[first_matches])
+                    .replace(last_matches, numbers[last_matches]);
+            } else {
+                // overlap
+                let overlap_size = (first_matches_index + first_matches.len()) - last_matches_index;
+                let truncate_index = first_matches.len() - overlap_size;
+                let new_pattern = format!("{}{}", &first_matches[..truncate_index], last_matches);
+                *o = o.replace(
+                    &new_pattern,
+                    &format!("{}{}", numbers[first_matches], numbers[last_matches]),
+                );
+                log::trace!("overlap_size = {:?}", overlap_size);
+                log::trace!("new_pattern = {:?}", new_pattern);
+            }
+        }
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Model = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        let input = lines(input);
+        for (index, line) in input.iter().enumerate() {
+            let spelled = NUMBERS.iter().any(|(word, _)| line.contains(word));
+            if !spelled && digits(line).is_empty() {
+                return Err(ParseError::new(
+                    index + 1,
+                    1,
+                    line,
+                    "at least one digit, written out or not",
+                ));
+            }
+        }
+        Ok(input)
+    }
+
+    fn part1(model: &Vec<String>) -> u32 {
+        model
+            .iter()
+            .filter_map(|o| {
+                let digits = digits(o);
+                // Only written out digits, part 1 cannot make sense of the line
+                if digits.is_empty() {
+                    log::warn!("no digit in {:?}", o);
+                    None
+                } else {
+                    Some(calibration_value(digits))
+                }
+            })
+            .sum()
+    }
+
+    fn part2(model: &Vec<String>) -> u32 {
+        let numbers: HashMap<&str, &str> = NUMBERS.into_iter().collect();
+
+        model
+            .iter()
+            .map(|o| {
+                let mut o = o.clone();
+                replace_spelled_digits(&mut o, &numbers);
+                log::trace!("o = {:?}", o);
+                let digits = digits(&o);
+                log::trace!("digits = {:?}", digits);
+                calibration_value(digits)
+            })
+            .sum()
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day01>();
+    }
+
+    #[test]
+    fn test_overlap() {
+        let model = Day01::parse(indoc!(
+            "
+            oneight
+            "
+        ))
+        .unwrap();
+        assert_eq!(Day01::part2(&model), 18);
+    }
+
+    #[test]
+    fn test_overlap2() {
+        // Overlap possibilities
+        // oneight, twone, threeight, fiveight, sevenine, eightwo, eighthree, nineight
+        let model = Day01::parse(indoc!(
+            "
+            oneight
+            twone
+            threeight
+            fiveight
+            sevenine
+            eightwo
+            eighthree
+            nineight
+            15qhpvsevensixoneightt
+            "
+        ))
+        .unwrap();
+        assert_eq!(
+            Day01::part2(&model),
+            18 + 21 + 38 + 58 + 79 + 82 + 83 + 98 + 18
+        );
+    }
+
+    #[test]
+    fn test_no_overlap() {
+        let model = Day01::parse(indoc!(
+            "
+            oneeight
+            "
+        ))
+        .unwrap();
+        assert_eq!(Day01::part2(&model), 18);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day01::parse("1abc2\nnothing\n"),
+            Err(ParseError::new(
+                2,
+                1,
+                "nothing",
+                "at least one digit, written out or not"
+            ))
+        );
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_01::Day01;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day01>()
+}
@@ -0,0 +1,243 @@
+//! Day 2: Cube Conundrum
+//!
+//! The model maps each game id to the [`Cubes`] shown during it.
+
+use std::collections::HashMap;
+
+use aoc_common::parse::{finish, lines, IResult, ParseError};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{space1, u32},
+    combinator::{cut, value},
+    error::context,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+};
+
+/// The cubes shown during a game, per colour. Which draw they were shown
+/// in does not matter.
+#[derive(Debug)]
+pub struct Cubes {
+    pub red: Vec<u32>,
+    pub green: Vec<u32>,
+    pub blue: Vec<u32>,
+}
+
+#[derive(Debug)]
+struct Bag {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+#[derive(PartialEq, Debug, Eq)]
+enum Solution {
+    Possible,
+    NotPossible,
+}
+
+fn is_posible(game_cubes: &Cubes, bag: &Bag) -> Solution {
+    if game_cubes.red.iter().all(|x| x <= &bag.red)
+        && game_cubes.green.iter().all(|x| x <= &bag.green)
+        && game_cubes.blue.iter().all(|x| x <= &bag.blue)
+    {
+        Solution::Possible
+    } else {
+        Solution::NotPossible
+    }
+}
+
+/// Power of the smallest set of cubes the game could be played with.
+pub fn get_power(game_cubes: &Cubes) -> u32 {
+    game_cubes.red.iter().max().unwrap()
+        * game_cubes.green.iter().max().unwrap()
+        * game_cubes.blue.iter().max().unwrap()
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+fn parse_color(input: &str) -> IResult<&str, (u32, Color)> {
+    separated_pair(
+        u32,
+        space1,
+        cut(context(
+            "a color (red, green or blue)",
+            alt((
+                value(Color::Red, tag("red")),
+                value(Color::Green, tag("green")),
+                value(Color::Blue, tag("blue")),
+            )),
+        )),
+    )(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, (u32, Cubes)> {
+    let (input, (id, colors)) = separated_pair(
+        preceded(tag("Game "), u32),
+        tag(": "),
+        // Draws are separated by ';' but they don't matter, only the counts do
+        separated_list1(alt((tag(", "), tag("; "))), parse_color),
+    )(input)?;
+
+    let mut cube = Cubes {
+        red: Vec::new(),
+        green: Vec::new(),
+        blue: Vec::new(),
+    };
+    for (value, color) in colors {
+        match color {
+            Color::Red => cube.red.push(value),
+            Color::Green => cube.green.push(value),
+            Color::Blue => cube.blue.push(value),
+        }
+    }
+    Ok((input, (id, cube)))
+}
+
+fn parse(input: Vec<String>) -> Result<HashMap<u32, Cubes>, ParseError> {
+    let mut data: HashMap<u32, Cubes> = HashMap::new();
+    for (index, line) in input.iter().enumerate() {
+        let (id, cube) = finish(line, parse_line).map_err(|e| ParseError {
+            line: index + 1,
+            ..e
+        })?;
+        data.insert(id, cube);
+    }
+    log::debug!("data = {:?}", data);
+    Ok(data)
+}
+
+pub struct Day02;
+
+// The `Solution` enum above is about a single game, hence the full path
+impl aoc_common::Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Model = HashMap<u32, Cubes>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<HashMap<u32, Cubes>, ParseError> {
+        parse(lines(input))
+    }
+
+    fn part1(data: &HashMap<u32, Cubes>) -> u32 {
+        let bag: Bag = Bag {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+
+        data.iter()
+            .filter_map(|(key, value)| {
+                log::trace!("key = {:?}, value = {:?}", key, value);
+                match is_posible(value, &bag) {
+                    Solution::Possible => Some(key),
+                    Solution::NotPossible => None,
+                }
+            })
+            .sum()
+    }
+
+    fn part2(data: &HashMap<u32, Cubes>) -> u32 {
+        data.iter()
+            .map(|(key, value)| {
+                log::trace!("key = {:?}, value = {:?}", key, value);
+                get_power(value)
+            })
+            .sum()
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use aoc_common::Solution as _;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day02>();
+    }
+
+    #[test]
+    fn test_is_posible() {
+        let game_cubes1 = Cubes {
+            red: Vec::from([10]),
+            green: Vec::from([10]),
+            blue: Vec::from([10]),
+        };
+        let game_cubes2 = Cubes {
+            red: Vec::from([10, 10, 11]),
+            green: Vec::from([10]),
+            blue: Vec::from([10]),
+        };
+        let game_cubes3 = Cubes {
+            red: Vec::from([10]),
+            green: Vec::from([10]),
+            blue: Vec::from([15]),
+        };
+
+        let bag = Bag {
+            red: 10,
+            green: 10,
+            blue: 10,
+        };
+        assert_eq!(is_posible(&game_cubes1, &bag), Solution::Possible);
+        assert_eq!(is_posible(&game_cubes2, &bag), Solution::NotPossible);
+        assert_eq!(is_posible(&game_cubes3, &bag), Solution::NotPossible);
+    }
+
+    #[test]
+    fn test_get_power() {
+        let game_cubes1 = Cubes {
+            red: Vec::from([10]),
+            green: Vec::from([10]),
+            blue: Vec::from([10]),
+        };
+        let game_cubes2 = Cubes {
+            red: Vec::from([10, 10, 11]),
+            green: Vec::from([10]),
+            blue: Vec::from([10]),
+        };
+        let game_cubes3 = Cubes {
+            red: Vec::from([10]),
+            green: Vec::from([10]),
+            blue: Vec::from([15]),
+        };
+        assert_eq!(get_power(&game_cubes1), 1000);
+        assert_eq!(get_power(&game_cubes2), 1100);
+        assert_eq!(get_power(&game_cubes3), 1500);
+    }
+    #[test]
+    fn test_parse_error() {
+        let input = indoc!(
+            "
+            Game 1: 3 blue, 4 red
+            Game 2: 1 blue, 2 purple
+            "
+        );
+        assert_eq!(
+            Day02::parse(input).unwrap_err(),
+            ParseError::new(
+                2,
+                19,
+                "Game 2: 1 blue, 2 purple",
+                "a color (red, green or blue)"
+            )
+        );
+    }
+}
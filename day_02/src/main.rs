@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_02::Day02;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day02>()
+}
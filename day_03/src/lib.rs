@@ -1,89 +1,21 @@
-#![allow(dead_code)]
-use std::{char, ops::Add};
-
-fn parse_input(input: Option<&str>) -> Vec<String> {
-    let input = match input {
-        None => include_str!("../../input.txt"),
-        Some(x) => x,
-    };
-    let output = input
-        .strip_suffix('\n')
-        .unwrap()
-        .split('\n')
-        .map(|o| o.to_string())
-        .collect::<Vec<String>>();
-
-    output
-}
-
-struct Grid {
-    length: usize,
-    data: Vec<char>,
-}
-
-impl Grid {
-    fn new(input: Vec<String>) -> Self {
-        let length = input[0].len();
-        let data: Vec<char> = input.join("").chars().collect();
-        Self { length, data }
-    }
-
-    fn get(&self, coord: Coord) -> Option<Character> {
-        if coord.x < 0 || coord.y < 0 {
-            return None;
-        }
-        // The grid is a square x == y
-        if coord.x >= self.length as isize || coord.y >= self.length as isize {
-            return None;
-        }
-        Some(Character {
-            value: self.data[coord.y as usize * self.length + coord.x as usize],
-            coord,
-        })
-    }
+//! Day 3: Gear Ratios
+//!
+//! The model is the engine schematic as a [`Grid`]. [`part_numbers`] and
+//! [`gear_ratios`] list what the two parts add up.
 
-    // Get all neighbours clockwise
-    fn get_neighbours(&self, coord: Coord) -> Vec<Option<Character>> {
-        let mut neighbours = Vec::new();
-        let neighbours_coords: Vec<Coord> = Vec::from([
-            (1, 0).into(),
-            (1, 1).into(),
-            (0, 1).into(),
-            (-1, 1).into(),
-            (-1, 0).into(),
-            (-1, -1).into(),
-            (0, -1).into(),
-            (1, -1).into(),
-        ]);
-
-        for neighbour_coord in neighbours_coords {
-            neighbours.push(self.get(coord + neighbour_coord));
-        }
+use aoc_common::{parse::ParseError, Coord, Grid, Solution, NEIGHBOURS8};
+use std::char;
 
-        neighbours
-    }
+fn character(grid: &Grid<char>, coord: Coord) -> Option<Character> {
+    grid.get(coord).map(|&value| Character { value, coord })
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
-struct Coord {
-    x: isize,
-    y: isize,
-}
-
-impl From<(isize, isize)> for Coord {
-    fn from((x, y): (isize, isize)) -> Self {
-        Self { x, y }
-    }
-}
-
-impl Add<Coord> for Coord {
-    type Output = Coord;
-    fn add(self, rhs: Coord) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
+// Get all neighbours clockwise
+fn get_neighbours(grid: &Grid<char>, coord: Coord) -> Vec<Option<Character>> {
+    NEIGHBOURS8
+        .iter()
+        .map(|neighbour_coord| character(grid, coord + *neighbour_coord))
+        .collect()
 }
 
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -98,12 +30,12 @@ impl Number {
         }
     }
 
-    fn filter_part_number(&mut self, grid: &Grid) {
+    fn filter_part_number(&mut self, grid: &Grid<char>) {
         let mut part_number = Vec::new();
         for number in self.characters.iter() {
             let mut number_part_number = Vec::new();
             for character in number.iter() {
-                let neighbours = grid.get_neighbours(character.coord);
+                let neighbours = get_neighbours(grid, character.coord);
                 let neighbours: Vec<Character> = neighbours.iter().filter_map(|o| *o).collect();
                 let is_character_part_number = neighbours
                     .iter()
@@ -119,11 +51,11 @@ impl Number {
         self.characters = part_number;
     }
 
-    fn get_gears(&self, grid: &Grid, gears: Vec<Character>) -> Vec<usize> {
+    fn get_gears(&self, grid: &Grid<char>, gears: Vec<Character>) -> Vec<usize> {
         let gears_neighbours = gears
             .iter()
             .map(|c| {
-                grid.get_neighbours(c.coord)
+                get_neighbours(grid, c.coord)
                     .iter()
                     .filter_map(|o| *o)
                     .collect::<Vec<Character>>()
@@ -131,7 +63,7 @@ impl Number {
             .collect::<Vec<Vec<Character>>>();
         // dbg!(&gears_neighbours);
 
-        dbg!(&self.characters);
+        log::trace!("self.characters = {:?}", self.characters);
 
         let gears_part_contacts = gears_neighbours
             .iter()
@@ -179,9 +111,10 @@ impl Number {
             .map(|g| g.iter().product::<usize>())
             .collect::<Vec<usize>>();
 
-        dbg!(gears_part_contacts);
-        dbg!(gears_parts);
-        dbg!(gears_ratio)
+        log::trace!("gears_part_contacts = {:?}", gears_part_contacts);
+        log::trace!("gears_parts = {:?}", gears_parts);
+        log::trace!("gears_ratio = {:?}", gears_ratio);
+        gears_ratio
     }
 
     fn get_numbers(&self) -> Vec<u32> {
@@ -209,15 +142,15 @@ struct Character {
     coord: Coord,
 }
 
-fn run(input: Vec<String>) -> u32 {
-    let grid = Grid::new(input);
+// Numbers as runs of digits, and the gears ('*') between them
+fn scan(grid: &Grid<char>) -> (Number, Vec<Character>) {
     let mut numbers: Number = Number::new();
     let mut gears: Vec<Character> = Vec::new();
-    for y in 0..grid.length {
+    for y in 0..grid.height() {
         let mut characters: Vec<Character> = Vec::new();
-        for x in 0..grid.length {
+        for x in 0..grid.width() {
             let coord: Coord = (x as isize, y as isize).into();
-            match grid.get(coord) {
+            match character(grid, coord) {
                 Some(character) if character.value == '*' => {
                     gears.push(character);
                     if !characters.is_empty() {
@@ -246,17 +179,45 @@ fn run(input: Vec<String>) -> u32 {
             numbers.characters.push(characters.clone());
         }
     }
-    // dbg!(&numbers);
-    let ratio = numbers.get_gears(&grid, gears);
-    ratio.iter().sum::<usize>() as u32
+    (numbers, gears)
 }
 
-fn main() {
-    let input = parse_input(None);
+/// The numbers next to a symbol, diagonals included, row after row.
+pub fn part_numbers(grid: &Grid<char>) -> Vec<u32> {
+    let (mut numbers, _) = scan(grid);
+    log::debug!("numbers = {:?}", numbers);
+    numbers.filter_part_number(grid);
+    numbers.get_numbers()
+}
 
-    let answer = run(input);
+/// The ratio of every gear: the product of the two numbers next to a `*`.
+pub fn gear_ratios(grid: &Grid<char>) -> Vec<usize> {
+    let (numbers, gears) = scan(grid);
+    numbers.get_gears(grid, gears)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Model = Grid<char>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(input)
+    }
 
-    println!("Answer: {}", answer);
+    fn part1(grid: &Grid<char>) -> u32 {
+        let part_number = part_numbers(grid);
+        log::debug!("part_number = {:?}", part_number);
+        part_number.iter().sum()
+    }
+
+    fn part2(grid: &Grid<char>) -> u32 {
+        gear_ratios(grid).iter().sum::<usize>() as u32
+    }
 }
 
 #[allow(unused_imports)]
@@ -272,9 +233,14 @@ mod tests {
         assert_eq!(1, 1);
     }
 
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day03>();
+    }
+
     #[test]
     fn test_get() {
-        let input = parse_input(Some(indoc!(
+        let grid = Grid::parse(indoc!(
             "
             467..114..
             ...*......
@@ -287,63 +253,62 @@ mod tests {
             ...$.*....
             .664.598..
             "
-        )));
-        dbg!(&input);
-        let grid = Grid::new(input);
+        ))
+        .unwrap();
 
         assert_eq!(
-            grid.get((0, 0).into()),
+            character(&grid, (0, 0).into()),
             Some(Character {
                 value: '4',
                 coord: (0, 0).into()
             })
         );
         assert_eq!(
-            grid.get((2, 0).into()),
+            character(&grid, (2, 0).into()),
             Some(Character {
                 value: '7',
                 coord: (2, 0).into()
             })
         );
         assert_eq!(
-            grid.get((3, 0).into()),
+            character(&grid, (3, 0).into()),
             Some(Character {
                 value: '.',
                 coord: (3, 0).into()
             })
         );
         assert_eq!(
-            grid.get((9, 0).into()),
+            character(&grid, (9, 0).into()),
             Some(Character {
                 value: '.',
                 coord: (9, 0).into()
             })
         );
         assert_eq!(
-            grid.get((1, 9).into()),
+            character(&grid, (1, 9).into()),
             Some(Character {
                 value: '6',
                 coord: (1, 9).into()
             })
         );
         assert_eq!(
-            grid.get((5, 8).into()),
+            character(&grid, (5, 8).into()),
             Some(Character {
                 value: '*',
                 coord: (5, 8).into()
             })
         );
         assert_eq!(
-            grid.get((9, 9).into()),
+            character(&grid, (9, 9).into()),
             Some(Character {
                 value: '.',
                 coord: (9, 9).into()
             })
         );
-        assert_eq!(grid.get((10, 0).into()), None);
-        assert_eq!(grid.get((10, 0).into()), None);
-        assert_eq!(grid.get((-1, 0).into()), None);
-        assert_eq!(grid.get((0, -1).into()), None);
+        assert_eq!(character(&grid, (10, 0).into()), None);
+        assert_eq!(character(&grid, (10, 0).into()), None);
+        assert_eq!(character(&grid, (-1, 0).into()), None);
+        assert_eq!(character(&grid, (0, -1).into()), None);
     }
 
     #[test]
@@ -355,7 +320,7 @@ mod tests {
 
     #[test]
     fn test_get_neighbours() {
-        let input = parse_input(Some(indoc!(
+        let grid = Grid::parse(indoc!(
             "
             467..114..
             ...*......
@@ -368,13 +333,11 @@ mod tests {
             ...$.*....
             .664.598..
             "
-        )));
-        dbg!(&input);
-        let grid = Grid::new(input);
+        ))
+        .unwrap();
 
-        dbg!(grid.get_neighbours((0, 0).into()));
         assert_eq!(
-            grid.get_neighbours((0, 0).into()),
+            get_neighbours(&grid, (0, 0).into()),
             vec![
                 Some(Character {
                     value: '6',
@@ -396,9 +359,8 @@ mod tests {
             ]
         );
 
-        dbg!(grid.get_neighbours((2, 2).into()));
         assert_eq!(
-            grid.get_neighbours((2, 2).into()),
+            get_neighbours(&grid, (2, 2).into()),
             vec![
                 Some(Character {
                     value: '5',
@@ -435,9 +397,8 @@ mod tests {
             ]
         );
 
-        dbg!(grid.get_neighbours((6, 3).into()));
         assert_eq!(
-            grid.get_neighbours((6, 3).into()),
+            get_neighbours(&grid, (6, 3).into()),
             vec![
                 Some(Character {
                     value: '.',
@@ -474,48 +435,4 @@ mod tests {
             ]
         );
     }
-
-    #[test]
-    fn test_run() {
-        let input = parse_input(Some(indoc!(
-            "
-            467..114..
-            ...*......
-            ..35..633.
-            ......#...
-            617*......
-            .....+.58.
-            ..592.....
-            ......755.
-            ...$.*....
-            .664.598..
-            "
-        )));
-        dbg!(&input);
-        let answer = run(input);
-        assert_eq!(answer, 467835);
-    }
-
-    #[test]
-    fn test_run2() {
-        let input = parse_input(Some(indoc!(
-            "
-            12.......*..
-            +.........34
-            .......-12..
-            ..78........
-            ..*....60...
-            78.........9
-            .5.....23..$
-            8...90*12...
-            ............
-            2.2......12.
-            .*.........*
-            1.1..503+.56
-            "
-        )));
-        dbg!(&input);
-        let answer = run(input);
-        assert_eq!(answer, 31600);
-    }
 }
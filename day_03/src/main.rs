@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_03::Day03;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day03>()
+}
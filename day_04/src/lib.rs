@@ -0,0 +1,151 @@
+//! Day 4: Scratchcards
+//!
+//! The model is the list of cards, as [`Deck`]s.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+
+use aoc_common::{
+    checked,
+    parse::{finish, lines, IResult, ParseError},
+    Solution,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, space1, u32},
+    multi::separated_list1,
+    sequence::{delimited, pair, preceded, separated_pair},
+};
+
+/// One scratchcard: the numbers we have and the winning ones.
+#[derive(Debug)]
+pub struct Deck {
+    pub id: u32,
+    pub cards: Vec<u32>,
+    pub solutions: HashSet<u32>,
+}
+
+fn parse_numbers(input: &str) -> IResult<&str, Vec<u32>> {
+    preceded(space1, separated_list1(space1, u32))(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Deck> {
+    let (input, id) = delimited(pair(tag("Card"), space1), u32, char(':'))(input)?;
+    let (input, (cards, solutions)) =
+        separated_pair(parse_numbers, pair(space1, char('|')), parse_numbers)(input)?;
+    Ok((
+        input,
+        Deck {
+            id,
+            cards,
+            solutions: solutions.into_iter().collect(),
+        },
+    ))
+}
+
+fn parse(input: Vec<String>) -> Result<Vec<Deck>, ParseError> {
+    let mut deck = Vec::new();
+    for (index, line) in input.iter().enumerate() {
+        deck.push(finish(line, parse_line).map_err(|e| ParseError {
+            line: index + 1,
+            ..e
+        })?);
+    }
+    log::debug!("deck = {:?}", deck);
+    Ok(deck)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Model = Vec<Deck>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Deck>, ParseError> {
+        parse(lines(input))
+    }
+
+    fn part1(deck: &Vec<Deck>) -> usize {
+        let sol = deck
+            .iter()
+            .map(|d| {
+                d.cards.iter().fold(0usize, |mut point, c| {
+                    //
+                    if d.solutions.contains(c) {
+                        if point == 0 {
+                            point = 1;
+                        } else {
+                            point *= 2;
+                        }
+                    }
+                    point
+                })
+            })
+            .collect::<Vec<usize>>();
+
+        log::debug!("sol = {:?}", sol);
+        sol.iter().sum()
+    }
+
+    fn part2(deck: &Vec<Deck>) -> usize {
+        let sol = deck
+            .iter()
+            .map(|d| {
+                d.cards.iter().fold((0usize, 0usize), |mut matches, c| {
+                    //
+                    if d.solutions.contains(c) {
+                        matches.1 += 1;
+                    }
+                    (d.id as usize, matches.1)
+                })
+            })
+            .collect::<BTreeMap<usize, usize>>();
+
+        // Create initial hand by adding all original cards
+        let initial_hand = sol
+            .keys()
+            .map(|id| (*id, 1))
+            .collect::<BTreeMap<usize, usize>>();
+
+        let instances = sol
+            .iter()
+            // .enumerate()
+            .fold(initial_hand, |mut hand, (id, card_solutions)| {
+                let card_instance = *hand.get(id).unwrap();
+
+                for copy_id in (id + 1)..(id + 1 + *card_solutions) {
+                    hand.entry(copy_id).and_modify(|value| {
+                        *value = checked::add(*value, card_instance);
+                    });
+                }
+                hand
+            });
+
+        log::debug!("sol = {:?}", sol);
+        log::debug!("instances = {:?}", instances);
+
+        checked::sum(instances.values().copied())
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day04>();
+    }
+}
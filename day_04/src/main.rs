@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_04::Day04;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day04>()
+}
@@ -1,30 +1,32 @@
+//! Day 5: If You Give A Seed A Fertilizer
+//!
+//! The model is the almanac ([`Data`]), [`get_location`] follows one seed
+//! through it.
+
 use std::{collections::HashMap, ops::Range};
 
 use nom::{
     bytes::complete::tag, character::complete::multispace1, multi::separated_list1, sequence::pair,
-    *,
 };
-use rayon::prelude::*;
-
-fn read_input(input: Option<&str>) -> String {
-    let input = match input {
-        None => include_str!("../../input.txt"),
-        Some(x) => x,
-    };
 
-    input.to_string()
-}
+use aoc_common::{
+    parse::{parse_input, IResult, ParseError},
+    Solution,
+};
+use rayon::prelude::*;
 
+/// The almanac. Every map sends a source range to the destination range
+/// it is shifted to, numbers outside of them map to themselves.
 #[derive(Debug)]
-struct Data {
-    seeds: Vec<usize>,
-    seed_to_soil: HashMap<Range<usize>, Range<usize>>,
-    soil_to_fertilizer: HashMap<Range<usize>, Range<usize>>,
-    fertilizer_to_water: HashMap<Range<usize>, Range<usize>>,
-    water_to_light: HashMap<Range<usize>, Range<usize>>,
-    light_to_temperature: HashMap<Range<usize>, Range<usize>>,
-    temperature_to_humidity: HashMap<Range<usize>, Range<usize>>,
-    humidity_to_location: HashMap<Range<usize>, Range<usize>>,
+pub struct Data {
+    pub seeds: Vec<usize>,
+    pub seed_to_soil: HashMap<Range<usize>, Range<usize>>,
+    pub soil_to_fertilizer: HashMap<Range<usize>, Range<usize>>,
+    pub fertilizer_to_water: HashMap<Range<usize>, Range<usize>>,
+    pub water_to_light: HashMap<Range<usize>, Range<usize>>,
+    pub light_to_temperature: HashMap<Range<usize>, Range<usize>>,
+    pub temperature_to_humidity: HashMap<Range<usize>, Range<usize>>,
+    pub humidity_to_location: HashMap<Range<usize>, Range<usize>>,
 }
 
 fn parse_range(input: &str) -> IResult<&str, (Range<usize>, Range<usize>)> {
@@ -98,22 +100,49 @@ fn parse(input: &str) -> IResult<&str, Data> {
     ))
 }
 
-fn run(input: String) -> usize {
-    let (_, data) = parse(&input).unwrap();
-    // dbg!(&data);
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Model = Data;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Data, ParseError> {
+        parse_input(input, parse)
+    }
+
+    fn part1(data: &Data) -> usize {
+        log::debug!("data = {:?}", data);
+
+        let locations = data
+            .seeds
+            .iter()
+            .map(|seed| get_location(data, *seed))
+            .collect::<Vec<usize>>();
+
+        log::debug!("locations = {:?}", locations);
+        *locations.iter().min().unwrap()
+    }
+
+    fn part2(data: &Data) -> usize {
+        // dbg!(&data);
 
-    let locations = data
-        .seeds
-        .par_chunks(2)
-        .flat_map(|x| x[0]..(x[0] + x[1]))
-        .map(|seed| get_location(&data, seed))
-        .min()
-        .unwrap();
+        let locations = data
+            .seeds
+            .par_chunks(2)
+            .flat_map(|x| x[0]..(x[0] + x[1]))
+            .map(|seed| get_location(data, seed))
+            .min()
+            .unwrap();
 
-    locations
+        locations
+    }
 }
 
-fn get_location(data: &Data, src: usize) -> usize {
+/// The location a seed ends up at, through every map of the almanac.
+pub fn get_location(data: &Data, src: usize) -> usize {
     let mut dst = get_map_location(&data.seed_to_soil, src);
     dst = get_map_location(&data.soil_to_fertilizer, dst);
     dst = get_map_location(&data.fertilizer_to_water, dst);
@@ -133,14 +162,6 @@ fn get_map_location(map: &HashMap<Range<usize>, Range<usize>>, src: usize) -> us
     src
 }
 
-fn main() {
-    let input = read_input(None);
-
-    let answer = run(input);
-
-    println!("Answer: {}", answer);
-}
-
 #[allow(unused_imports)]
 #[cfg(test)]
 mod tests {
@@ -155,46 +176,7 @@ mod tests {
     }
 
     #[test]
-    fn test_run() {
-        let input = read_input(Some(indoc!(
-            "
-            seeds: 79 14 55 13
-
-            seed-to-soil map:
-            50 98 2
-            52 50 48
-
-            soil-to-fertilizer map:
-            0 15 37
-            37 52 2
-            39 0 15
-
-            fertilizer-to-water map:
-            49 53 8
-            0 11 42
-            42 0 7
-            57 7 4
-
-            water-to-light map:
-            88 18 7
-            18 25 70
-
-            light-to-temperature map:
-            45 77 23
-            81 45 19
-            68 64 13
-
-            temperature-to-humidity map:
-            0 69 1
-            1 0 69
-
-            humidity-to-location map:
-            60 56 37
-            56 93 4
-            "
-        )));
-        dbg!(&input);
-        let answer = run(input);
-        assert_eq!(answer, 46);
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day05>();
     }
 }
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_05::Day05;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day05>()
+}
@@ -0,0 +1,118 @@
+//! Day 6: Wait For It
+//!
+//! The model lists the races ([`Data`]), [`ways_to_win`] solves one of them.
+
+use nom::{
+    bytes::complete::tag, character::complete::multispace1, multi::separated_list1,
+    sequence::delimited,
+};
+
+use aoc_common::{
+    params::{self, Param},
+    parse::{parse_input, IResult, ParseError},
+    Solution,
+};
+
+/// The races, as their times and the record distances in the same order.
+#[derive(Debug)]
+pub struct Data {
+    pub times: Vec<usize>,
+    pub distances: Vec<usize>,
+}
+
+fn parse(input: &str) -> IResult<&str, Data> {
+    let (input, _) = tag("Time: ")(input)?;
+    let (input, times) = delimited(
+        multispace1,
+        separated_list1(multispace1, nom::character::complete::u64),
+        tag("\n"),
+    )(input)?;
+    let (input, _) = tag("Distance: ")(input)?;
+    let (input, distances) = delimited(
+        multispace1,
+        separated_list1(multispace1, nom::character::complete::u64),
+        tag("\n"),
+    )(input)?;
+
+    let times = times.into_iter().map(|x| x as usize).collect();
+    let distances = distances.into_iter().map(|x| x as usize).collect();
+    Ok((input, Data { times, distances }))
+}
+
+/// Number of ways to beat `best_distance` in a race lasting `time`, the boat
+/// going `dist_per_ms` faster for every millisecond the button is held.
+pub fn ways_to_win(time: usize, best_distance: usize, dist_per_ms: usize) -> usize {
+    (0..=time)
+        .map(move |press_time| {
+            // Calculate distance
+            let speed = press_time * dist_per_ms;
+            (time - press_time) * speed
+        })
+        .filter(|distance| *distance > best_distance)
+        .count()
+}
+
+// Part 2 reads the numbers of a line as one, ignoring the spaces
+fn join(numbers: &[usize]) -> usize {
+    numbers
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<String>()
+        .parse()
+        .unwrap()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "dist_per_ms",
+        default: "1",
+        doc: "How much faster the boat goes for every millisecond the button is held",
+        check: params::at_least::<0>,
+    }];
+
+    type Model = Data;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Data, ParseError> {
+        parse_input(input, parse)
+    }
+
+    fn part1(data: &Data) -> usize {
+        log::debug!("data = {:?}", data);
+
+        let dist_per_ms = params::get::<Self, usize>("dist_per_ms").expect("checked when loaded");
+        data.times
+            .iter()
+            .zip(data.distances.iter())
+            .map(|(time, best_distance)| ways_to_win(*time, *best_distance, dist_per_ms))
+            .product::<usize>()
+    }
+
+    fn part2(data: &Data) -> usize {
+        let dist_per_ms = params::get::<Self, usize>("dist_per_ms").expect("checked when loaded");
+        ways_to_win(join(&data.times), join(&data.distances), dist_per_ms)
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day06>();
+    }
+}
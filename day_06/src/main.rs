@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_06::Day06;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day06>()
+}
@@ -1,23 +1,35 @@
+//! Day 7: Camel Cards
+//!
+//! The model maps every hand to its bid. [`total_winnings`] solves both
+//! parts, with or without jokers.
+
 use std::collections::HashMap;
 
-use nom::{character::complete::multispace1, multi::separated_list1, *};
+use nom::{
+    character::complete::{anychar, multispace1, satisfy},
+    combinator::{cut, peek, recognize},
+    error::context,
+    multi::{count, separated_list1},
+    sequence::terminated,
+};
 
-fn read_input(input: Option<&str>) -> String {
-    let input = match input {
-        None => include_str!("../../input.txt"),
-        Some(x) => x,
-    };
+use aoc_common::{
+    parse::{parse_input, IResult, ParseError},
+    Solution,
+};
 
-    input.to_string()
-}
+/// The cards, from the strongest.
+const CARDS: &str = "AKQJT98765432";
 
-fn get_card_value(card: char) -> u32 {
+// With jokers, 'J' is the weakest card instead of a jack
+fn get_card_value(card: char, jokers: bool) -> u32 {
     match card {
         //A, K, Q, J, T, 9, 8, 7, 6, 5, 4, 3, or 2
         'A' => 14,
         'K' => 13,
         'Q' => 12,
-        'J' => 1,
+        'J' if jokers => 1,
+        'J' => 11,
         'T' => 10,
         '9' => 9,
         '8' => 8,
@@ -31,7 +43,9 @@ fn get_card_value(card: char) -> u32 {
     }
 }
 
-fn get_hand_value_and_card_values(hand: &str) -> (u32, Vec<u32>) {
+/// The strength of a hand: the value of its type, then the values of its
+/// cards in order, to be compared in this order.
+pub fn get_hand_value_and_card_values(hand: &str, jokers: bool) -> (u32, Vec<u32>) {
     let mut hand_value = 0;
     let mut card_values = Vec::new();
 
@@ -42,11 +56,11 @@ fn get_hand_value_and_card_values(hand: &str) -> (u32, Vec<u32>) {
         let count = card_occurences.entry(card).or_insert(0);
         *count += 1;
         // Create a vector of card values
-        card_values.push(get_card_value(card));
+        card_values.push(get_card_value(card, jokers));
     }
 
     // Do we have jokers ?
-    match card_occurences.get(&'J') {
+    match card_occurences.get(&'J').filter(|_| jokers) {
         Some(nb_occurences) => match nb_occurences {
             5 => {
                 card_occurences.insert('A', 5);
@@ -63,7 +77,7 @@ fn get_hand_value_and_card_values(hand: &str) -> (u32, Vec<u32>) {
             _ => panic!("Unexpected number of jokers"),
         },
         None => {
-            dbg!("no jokers");
+            log::trace!("\"no jokers\" = {:?}", "no jokers");
         }
     };
 
@@ -78,8 +92,13 @@ fn get_hand_value_and_card_values(hand: &str) -> (u32, Vec<u32>) {
         }
     }
 
-    dbg!(&card_occurences);
-    dbg!((hand_value, card_values))
+    log::trace!("card_occurences = {:?}", card_occurences);
+    log::trace!(
+        "hand_value = {:?}, card_values = {:?}",
+        hand_value,
+        card_values
+    );
+    (hand_value, card_values)
 }
 
 fn find_best_hand(card_occurences: &mut HashMap<char, u32>, occurences: u32) {
@@ -88,7 +107,7 @@ fn find_best_hand(card_occurences: &mut HashMap<char, u32>, occurences: u32) {
         .map(|(k, v)| (*k, *v))
         .filter(|(k, _v)| *k != 'J')
         .collect::<Vec<_>>();
-    other_cards.sort_by_key(|v| (v.1, get_card_value(v.0)));
+    other_cards.sort_by_key(|v| (v.1, get_card_value(v.0, true)));
     let highest_card = other_cards.pop().unwrap();
     card_occurences
         .entry(highest_card.0)
@@ -105,20 +124,28 @@ fn parse(input: &str) -> IResult<&str, HashMap<String, u32>> {
 }
 
 fn parse_line(input: &str) -> IResult<&str, (String, u32)> {
-    let (input, hand) = nom::character::complete::alphanumeric1(input)?;
+    // Once a line starts, it has to be a hand of five cards
+    let (input, _) = peek(anychar)(input)?;
+    let (input, hand) = cut(context(
+        "a hand of five cards among AKQJT98765432",
+        terminated(
+            recognize(count(satisfy(|c| CARDS.contains(c)), 5)),
+            peek(multispace1),
+        ),
+    ))(input)?;
     let (input, _) = multispace1(input)?;
     let (input, bid) = nom::character::complete::u32(input)?;
     Ok((input, (hand.to_string(), bid)))
 }
 
-fn run(input: String) -> usize {
-    let (_, data) = parse(&input).unwrap();
-    dbg!(&data);
+/// The sum of the bids times the ranks, `jokers` making 'J' a wildcard.
+pub fn total_winnings(data: &HashMap<String, u32>, jokers: bool) -> usize {
+    log::debug!("data = {:?}", data);
 
     let mut data = data
-        .into_iter()
-        .map(|(hand, bid)| ({ (get_hand_value_and_card_values(&hand), hand, bid) }))
-        .collect::<Vec<((u32, Vec<u32>), String, u32)>>();
+        .iter()
+        .map(|(hand, bid)| (get_hand_value_and_card_values(hand, jokers), hand, bid))
+        .collect::<Vec<((u32, Vec<u32>), &String, &u32)>>();
 
     // Sort by hand value, then by card values
     data.sort_by_key(|((hand_value, card_values), _, _)| (*hand_value, card_values.clone()));
@@ -126,22 +153,42 @@ fn run(input: String) -> usize {
     data.iter()
         .enumerate()
         .inspect(|(i, (value, hand, bid))| {
-            dbg!(i, value, hand, bid);
+            log::trace!(
+                "i = {:?}, value = {:?}, hand = {:?}, bid = {:?}",
+                i,
+                value,
+                hand,
+                bid
+            );
         })
         // index + 1 because rank starts at 1.
-        .map(|(i, (_value, _hand, bid))| (i + 1) * *bid as usize)
+        .map(|(i, (_value, _hand, bid))| (i + 1) * **bid as usize)
         .inspect(|i| {
-            dbg!(i);
+            log::trace!("i = {:?}", i);
         })
         .sum::<usize>()
 }
 
-fn main() {
-    let input = read_input(None);
+pub struct Day07;
 
-    let answer = run(input);
+impl Solution for Day07 {
+    const DAY: u8 = 7;
 
-    println!("Answer: {}", answer);
+    type Model = HashMap<String, u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<HashMap<String, u32>, ParseError> {
+        parse_input(input, parse)
+    }
+
+    fn part1(data: &HashMap<String, u32>) -> usize {
+        total_winnings(data, false)
+    }
+
+    fn part2(data: &HashMap<String, u32>) -> usize {
+        total_winnings(data, true)
+    }
 }
 
 #[allow(unused_imports)]
@@ -157,9 +204,14 @@ mod tests {
         assert_eq!(1, 1);
     }
 
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day07>();
+    }
+
     #[test]
     fn test_various_j() {
-        let input = read_input(Some(indoc!(
+        let model = Day07::parse(indoc!(
             // KJJJJ 2  10
             // JJJJJ 1  4
             // KQJJJ 3  9
@@ -172,24 +224,25 @@ mod tests {
             K2JJQ 4
             K23AJ 5
             "
-        )));
-        let answer = run(input);
-        assert_eq!(answer, 36);
+        ))
+        .unwrap();
+        assert_eq!(Day07::part2(&model), 36);
     }
 
     #[test]
-    fn test_run() {
-        let input = read_input(Some(indoc!(
-            "
-            32T3K 765
-            T55J5 684
-            KK677 28
-            KTJJT 220
-            QQQJA 483
-            "
-        )));
-        dbg!(&input);
-        let answer = run(input);
-        assert_eq!(answer, 5905);
+    fn test_parse_error() {
+        let expected = "a hand of five cards among AKQJT98765432";
+        assert_eq!(
+            Day07::parse("32T3K 765\nKXZ12 5\n").unwrap_err(),
+            ParseError::new(2, 2, "KXZ12 5", expected)
+        );
+        assert_eq!(
+            Day07::parse("AAAAAA 1\n").unwrap_err(),
+            ParseError::new(1, 6, "AAAAAA 1", expected)
+        );
+        assert_eq!(
+            Day07::parse("AAAA 1\n").unwrap_err(),
+            ParseError::new(1, 5, "AAAA 1", expected)
+        );
     }
 }
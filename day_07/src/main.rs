@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_07::Day07;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day07>()
+}
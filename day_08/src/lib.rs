@@ -0,0 +1,354 @@
+//! Day 8: Haunted Wasteland
+//!
+//! The model is the map ([`Data`]): the left/right instructions and the
+//! network of nodes.
+
+use std::collections::{HashMap, HashSet};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, char, multispace1},
+    combinator::{cut, value},
+    error::context,
+    multi::{many1, separated_list1},
+    sequence::{delimited, separated_pair, tuple},
+    Offset,
+};
+
+use aoc_common::{
+    checked,
+    math::crt,
+    parse::{failure, parse_input, IResult, ParseError},
+    solution::Maybe,
+    stats, Solution,
+};
+
+fn parse(start: &str) -> IResult<&str, Data> {
+    let input = start;
+    let (input, directions) = many1(alt((
+        value(Direction::Left, char('L')),
+        value(Direction::Right, char('R')),
+    )))(input)?;
+    let (input, _) = cut(context("a direction, L or R", multispace1))(input)?;
+    let (input, lines) = separated_list1(tag("\n"), parse_line)(input)?;
+
+    // The ghosts would walk off the network on a node it does not define
+    let defined = lines
+        .iter()
+        .map(|(source, _)| *source)
+        .collect::<HashSet<_>>();
+    let undefined = lines
+        .iter()
+        .flat_map(|(_, (left, right))| [left, right])
+        .find(|node| !defined.contains(*node));
+    if let Some(node) = undefined {
+        return Err(failure(
+            &start[start.offset(*node)..],
+            "a node defined in the network",
+        ));
+    }
+    // Where the ghosts start and stop, said after the last node
+    if !defined.iter().any(|node| node.ends_with('A')) {
+        return Err(failure(input, "a node ending in A to start from"));
+    }
+    if !defined.iter().any(|node| node.ends_with('Z')) {
+        return Err(failure(input, "a node ending in Z to stop on"));
+    }
+    if defined.contains("AAA") && !defined.contains("ZZZ") {
+        return Err(failure(input, "the node ZZZ to go to from AAA"));
+    }
+
+    let destinations = lines
+        .into_iter()
+        .map(|(source, (left, right))| {
+            (
+                Location(source.to_string()),
+                Destination::from((left.to_string(), right.to_string())),
+            )
+        })
+        .collect();
+
+    let data = Data {
+        directions,
+        destinations,
+    };
+
+    // let data= hands.into_iter().collect();
+
+    Ok((input, data))
+}
+
+fn parse_line(input: &str) -> IResult<&str, (&str, (&str, &str))> {
+    let (input, source) = alphanumeric1(input)?;
+    // Once there is a source, the rest of the line has to follow
+    let (input, destinations) = cut(context(
+        "\" = (LEFT, RIGHT)\"",
+        delimited(
+            tuple((multispace1, tag("="), multispace1, tag("("))),
+            separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+            tag(")"),
+        ),
+    ))(input)?;
+    Ok((input, (source, destinations)))
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// Where a node leads to on the left and on the right.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Destination {
+    pub left: Location,
+    pub right: Location,
+}
+
+impl From<(String, String)> for Destination {
+    fn from((left, right): (String, String)) -> Self {
+        Self {
+            left: Location(left),
+            right: Location(right),
+        }
+    }
+}
+
+/// The name of a node.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Location(pub String);
+
+/// The map: the instructions, repeated forever, and the network of nodes.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Data {
+    pub directions: Vec<Direction>,
+    pub destinations: HashMap<Location, Destination>,
+}
+
+impl Data {
+    /// The node `direction` leads to from `node`.
+    fn step(&self, node: &Location, direction: Direction) -> Location {
+        let destination = &self.destinations[node];
+        match direction {
+            Direction::Left => destination.left.clone(),
+            Direction::Right => destination.right.clone(),
+        }
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Model = Data;
+    type Answer1 = Maybe<usize>;
+    type Answer2 = Maybe<usize>;
+
+    fn parse(input: &str) -> Result<Data, ParseError> {
+        parse_input(input, parse)
+    }
+
+    fn part1(data: &Data) -> Maybe<usize> {
+        log::debug!("data = {:?}", data);
+
+        let start = Location("AAA".to_string());
+        let end = Location("ZZZ".to_string());
+        if !data.destinations.contains_key(&start) {
+            return Maybe(None);
+        }
+
+        // Back on a node at the same instruction, the walk goes round forever
+        let mut seen = HashSet::new();
+        let mut current = start;
+        let mut iteration = 0;
+        loop {
+            let instruction = iteration % data.directions.len();
+            if !seen.insert((current.clone(), instruction)) {
+                return Maybe(None);
+            }
+            current = data.step(&current, data.directions[instruction]);
+
+            iteration += 1;
+            if current == end {
+                return Maybe(Some(iteration));
+            }
+        }
+    }
+
+    fn part2(data: &Data) -> Maybe<usize> {
+        log::debug!("data = {:?}", data);
+
+        let start_nodes = data
+            .destinations
+            .keys()
+            .filter(|loc| loc.0.ends_with('A'))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        log::debug!("start_nodes = {:?}", start_nodes);
+
+        // The first two times each ghost is on a Z node
+        let Some(iterations_to_z) = start_nodes
+            .iter()
+            .map(|start| z_hits(data, start))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Maybe(None);
+        };
+
+        log::debug!("iterations_to_z = {:?}", iterations_to_z);
+        stats::record("iterations_to_z", &iterations_to_z);
+        // Each ghost is back on a Z node every `second - first` steps, which
+        // does not have to be a multiple of when it first got there
+        let Some((meeting, period)) = crt(iterations_to_z
+            .iter()
+            .map(|hits| (hits[0] as i128, (hits[1] - hits[0]) as i128)))
+        else {
+            return Maybe(None);
+        };
+        let last_arrival = iterations_to_z.iter().map(|hits| hits[0]).max().unwrap() as i128;
+        let wait = (last_arrival - meeting).max(0);
+        Maybe(Some(checked::cast(
+            meeting + (wait + period - 1) / period * period,
+        )))
+    }
+}
+
+/// The first two times a ghost from `start` is on a Z node, `None` when it
+/// never is twice.
+fn z_hits(data: &Data, start: &Location) -> Option<[usize; 2]> {
+    // When the ghost was on each node at each instruction
+    let mut seen = HashMap::new();
+    let mut hits = Vec::new();
+    let mut current = start.clone();
+    let mut iteration = 0;
+    loop {
+        let instruction = iteration % data.directions.len();
+        if let Some(first) = seen.insert((current.clone(), instruction), iteration) {
+            // From `first` on the ghost goes round every `iteration - first`
+            // steps, the Z nodes it was on since then are on its way again
+            let period = iteration - first;
+            return match hits[..] {
+                [hit] if hit >= first => Some([hit, hit + period]),
+                _ => None,
+            };
+        }
+        current = data.step(&current, data.directions[instruction]);
+
+        iteration += 1;
+        if current.0.ends_with('Z') {
+            hits.push(iteration);
+            if let [first, second] = hits[..] {
+                return Some([first, second]);
+            }
+        }
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day08>();
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = indoc!(
+            "
+            RXL
+
+            AAA = (BBB, CCC)
+            "
+        );
+        assert_eq!(
+            Day08::parse(input).unwrap_err(),
+            ParseError::new(1, 2, "RXL", "a direction, L or R")
+        );
+
+        let input = indoc!(
+            "
+            RL
+
+            AAA = (BBB, CCC)
+            BBB = (DDD EEE)
+            "
+        );
+        assert_eq!(
+            Day08::parse(input).unwrap_err(),
+            ParseError::new(4, 11, "BBB = (DDD EEE)", "\" = (LEFT, RIGHT)\"")
+        );
+
+        let input = indoc!(
+            "
+            RL
+
+            AAA = (BBB, CCC)
+            BBB = (AAA, ZZZ)
+            CCC = (AAA, AAA)
+            "
+        );
+        assert_eq!(
+            Day08::parse(input).unwrap_err(),
+            ParseError::new(4, 13, "BBB = (AAA, ZZZ)", "a node defined in the network")
+        );
+
+        let error = |nodes: &str| Day08::parse(&format!("LR\n\n{}", nodes)).unwrap_err();
+        assert_eq!(
+            error("BBB = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n"),
+            ParseError::new(
+                4,
+                17,
+                "ZZZ = (ZZZ, ZZZ)",
+                "a node ending in A to start from"
+            )
+        );
+        assert_eq!(
+            error("AAA = (AAA, BBB)\nBBB = (AAA, AAA)\n"),
+            ParseError::new(4, 17, "BBB = (AAA, AAA)", "a node ending in Z to stop on")
+        );
+        assert_eq!(
+            error("AAA = (AAA, BBZ)\nBBZ = (AAA, AAA)\n"),
+            ParseError::new(4, 17, "BBZ = (AAA, AAA)", "the node ZZZ to go to from AAA")
+        );
+    }
+
+    #[test]
+    fn test_no_way() {
+        // ZZZ is never reached from AAA, nor 22Z by the second ghost
+        let data = Day08::parse(indoc!(
+            "
+            LR
+
+            AAA = (BBB, AAA)
+            BBB = (AAA, AAA)
+            ZZZ = (ZZZ, ZZZ)
+            22A = (22B, 22B)
+            22B = (22A, 22A)
+            22Z = (22Z, 22Z)
+            "
+        ))
+        .unwrap();
+        assert_eq!(Day08::part1(&data), Maybe(None));
+        assert_eq!(Day08::part2(&data), Maybe(None));
+        assert_eq!(z_hits(&data, &Location("22A".to_string())), None);
+
+        // Without AAA, only part 2 has an answer
+        let data = Day08::parse(include_str!("../fixtures/example_2.txt")).unwrap();
+        assert_eq!(Day08::part1(&data).to_string(), "none");
+        assert_eq!(Day08::part2(&data), Maybe(Some(6)));
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_08::Day08;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day08>()
+}
@@ -0,0 +1,157 @@
+//! Day 9: Mirage Maintenance
+//!
+//! The model holds the histories of the report, [`sequencies`] their
+//! successive differences, which both predictions are read from.
+
+use nom::{
+    character::complete::{line_ending, space1},
+    multi::separated_list1,
+};
+
+use aoc_common::{
+    parse::{failure, parse_input, IResult, ParseError},
+    Solution,
+};
+
+fn parse(input: &str) -> IResult<&str, Data> {
+    let (input, histories) = separated_list1(line_ending, parse_line)(input)?;
+
+    let data = Data { histories };
+
+    Ok((input, data))
+}
+
+fn parse_line(line: &str) -> IResult<&str, Vec<isize>> {
+    let (input, history) = separated_list1(space1, nom::character::complete::i64)(line)?;
+    let history = history
+        .into_iter()
+        .map(|x| x as isize)
+        .collect::<Vec<isize>>();
+    // Both predictions start from a row of zeroes
+    if !reaches_zeroes(&history) {
+        return Err(failure(
+            line,
+            "a history whose differences end up all zero before running out",
+        ));
+    }
+    Ok((input, history))
+}
+
+fn reaches_zeroes(history: &[isize]) -> bool {
+    let mut row = history.to_vec();
+    while !row.is_empty() {
+        if row.iter().all(|x| *x == 0) {
+            return true;
+        }
+        row = row.windows(2).map(|x| x[1] - x[0]).collect();
+    }
+    false
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Data {
+    pub histories: Vec<Vec<isize>>,
+}
+
+/// The differences between the values of each history, down to all zeroes.
+pub fn sequencies(data: &Data) -> Vec<Vec<Vec<isize>>> {
+    log::debug!("data = {:?}", data);
+
+    let sequencies = data
+        .histories
+        .iter()
+        .map(|h| recurse(h.clone(), Vec::new()))
+        .collect::<Vec<Vec<Vec<isize>>>>();
+
+    log::debug!("sequencies = {:?}", sequencies);
+    sequencies
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Model = Data;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Data, ParseError> {
+        parse_input(input, parse)
+    }
+
+    fn part1(data: &Data) -> isize {
+        let predictions = sequencies(data)
+            .iter()
+            .map(|s| {
+                s.iter()
+                    .rev()
+                    .map(|i| i.iter().last().unwrap())
+                    .sum::<isize>()
+            })
+            .sum::<isize>();
+
+        predictions
+    }
+
+    fn part2(data: &Data) -> isize {
+        let predictions = sequencies(data)
+            .iter()
+            .map(|s| {
+                s.iter()
+                    .rev()
+                    .map(|i| i.iter().next().unwrap())
+                    .fold(0, |acc, x| x - acc)
+            })
+            .sum::<isize>();
+
+        predictions
+    }
+}
+
+fn recurse(history: Vec<isize>, mut history_saved: Vec<Vec<isize>>) -> Vec<Vec<isize>> {
+    history_saved.push(history.clone());
+
+    if history.iter().all(|x| *x == 0) {
+        history_saved
+    } else {
+        let diff_each_step = history
+            .windows(2)
+            .map(|x| x[1] - x[0])
+            .collect::<Vec<isize>>();
+        recurse(diff_each_step, history_saved)
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day09>();
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let expected = "a history whose differences end up all zero before running out";
+        assert_eq!(
+            Day09::parse("0 3 6\n1\n").unwrap_err(),
+            ParseError::new(2, 1, "1", expected)
+        );
+        assert_eq!(
+            Day09::parse("1 2 4\n").unwrap_err(),
+            ParseError::new(1, 1, "1 2 4", expected)
+        );
+        assert_eq!(Day09::part1(&Day09::parse("0\n7 7\n").unwrap()), 7);
+    }
+}
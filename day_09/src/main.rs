@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_09::Day09;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day09>()
+}
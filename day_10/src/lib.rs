@@ -1,85 +1,68 @@
-use core::panic;
-use std::{collections::BTreeMap, ops::Add};
+//! Day 10: Pipe Maze
+//!
+//! The model is the field of pipes, [`walk_loop`] follows the loop going
+//! through the starting tile.
 
-use nom::{
-    bytes::complete::take_until, character::complete::line_ending, multi::many1,
-    sequence::terminated, *,
-};
+use core::panic;
+use std::collections::BTreeSet;
 
-fn read_input(input: Option<&str>) -> String {
-    let input = match input {
-        None => include_str!("../../input.txt"),
-        Some(x) => x,
-    };
+use nom::error::context;
 
-    input.to_string()
-}
+use aoc_common::{
+    geometry::winding_number,
+    get_neighbours,
+    grid::char_grid_with_start,
+    parse::{failure, parse_input, IResult, ParseError},
+    search::bfs,
+    stats, trace_map, Coord, Grid, Solution, TextMap, NEIGHBOURS,
+};
 
 fn parse(input: &str) -> IResult<&str, Data> {
-    let (input, pipes) = many1(parse_line)(input)?;
-
-    let data = pipes
-        .iter()
-        .enumerate()
-        .flat_map(|y| {
-            y.1.iter()
-                .enumerate()
-                .map(|x| (Coord::from((x.0 as isize, y.0 as isize)), *x.1))
-                .collect::<Vec<(Coord, char)>>()
-        })
-        .collect::<BTreeMap<Coord, char>>();
-
-    let data = Data { grid: data };
-
-    Ok((input, data))
-}
-
-fn parse_line(input: &str) -> IResult<&str, Vec<char>> {
-    let (input, pipe) = terminated(take_until("\n"), line_ending)(input)?;
-    let pipe = pipe.to_string().chars().collect();
-    Ok((input, pipe))
+    let (rest, grid) = context(
+        "a pipe | - L J 7 F, the ground . or the start S",
+        char_grid_with_start("|-LJ7F.S", 'S', "a start S in the map", "a single start S"),
+    )(input)?;
+    let data = Data { grid };
+    // The tiles are ASCII, each row is `width` bytes and a newline
+    if let Err(end) = follow_loop(&data) {
+        let offset = end.y as usize * (data.grid.width() + 1) + end.x as usize;
+        return Err(failure(
+            &input[offset..],
+            "a pipe leading on, the loop to go back to the start S",
+        ));
+    }
+    Ok((rest, data))
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Data {
-    grid: BTreeMap<Coord, char>,
+pub struct Data {
+    pub grid: Grid<char>,
 }
 
 impl Data {
     // Get all neighbours clockwise
     #[allow(dead_code)]
     fn get_neighbours(&self, coord: Coord) -> Vec<(Coord, char)> {
-        let neighbours_coords: Vec<Coord> =
-            Vec::from([(1, 0).into(), (0, 1).into(), (-1, 0).into(), (0, -1).into()]);
-
-        let neighbours = neighbours_coords
-            .iter()
-            .filter_map(|nc| self.grid.get(&(coord + *nc)).map(|v| (coord + *nc, *v)))
-            .collect::<Vec<(Coord, char)>>();
-        neighbours
+        get_neighbours(&self.grid, coord)
+            .into_iter()
+            .flatten()
+            .collect()
     }
 
-    fn next_pos(&self, current_pos: Coord, prev_pos: Coord) -> (Coord, char) {
-        println!("{:?}-{:?}", prev_pos, current_pos);
+    fn next_pos(&self, current_pos: Coord, prev_pos: Coord) -> Option<(Coord, char)> {
+        log::trace!("{:?}-{:?}", prev_pos, current_pos);
         let neighbours = self.get_neighbours_with_pipes(current_pos);
-        dbg!(neighbours
-            .into_iter()
-            .filter(|n| n.0 != prev_pos)
-            .nth(0)
-            .unwrap())
+        neighbours.into_iter().find(|n| n.0 != prev_pos)
     }
 
     // Get all possible neighbours clockwise depending on pipes
     fn get_neighbours_with_pipes(&self, coord: Coord) -> Vec<(Coord, char)> {
-        let neighbours_coords: Vec<Coord> =
-            Vec::from([(1, 0).into(), (0, 1).into(), (-1, 0).into(), (0, -1).into()]);
-
-        let neighbours = neighbours_coords
+        let neighbours = NEIGHBOURS
             .iter()
             .filter_map(|nc| {
                 //
-                let current_coord_value = self.grid.get(&coord).unwrap();
-                match self.grid.get(&(coord + *nc)) {
+                let current_coord_value = self.grid[coord];
+                match self.grid.get(coord + *nc) {
                     None => None,
                     Some(v) => {
                         let new_coord = coord + *nc;
@@ -291,57 +274,108 @@ impl Data {
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy, Ord, PartialOrd, Hash)]
-struct Coord {
-    x: isize,
-    y: isize,
-}
+/// The tiles of the loop from 'S' back to it, or the tile where the pipes
+/// stop leading on.
+fn follow_loop(data: &Data) -> Result<Vec<(Coord, char)>, Coord> {
+    let start = data.grid.position(|c| *c == 'S').unwrap();
+
+    let mut current_pos = (start, 'S');
+    let mut previous_pos = current_pos;
+    let mut tiles = Vec::new();
+    loop {
+        let new_pos = data
+            .next_pos(current_pos.0, previous_pos.0)
+            .ok_or(current_pos.0)?;
+        previous_pos = current_pos;
+        current_pos = new_pos;
+        tiles.push(current_pos);
 
-impl From<(isize, isize)> for Coord {
-    fn from((x, y): (isize, isize)) -> Self {
-        Self { x, y }
+        if current_pos.1 == 'S' {
+            return Ok(tiles);
+        }
     }
 }
 
-impl Add<Coord> for Coord {
-    type Output = Coord;
-    fn add(self, rhs: Coord) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
+/// Follow the pipes from 'S' back to it, returning the corners of the loop
+/// in order and every tile of the loop.
+pub fn walk_loop(data: &Data) -> (Vec<Coord>, BTreeSet<Coord>) {
+    log::debug!("data = {:?}", data);
+
+    let start = data.grid.position(|c| *c == 'S').unwrap();
+    let tiles = follow_loop(data).expect("checked when parsed");
+
+    let mut loop_edges = Vec::from([start]);
+    let mut loop_set = BTreeSet::new();
+    for (coord, tile) in tiles {
+        // Push loop coord into a set
+        if tile != '-' && tile != '|' {
+            loop_edges.push(coord);
         }
+        loop_set.insert(coord);
     }
+    (loop_edges, loop_set)
 }
 
-fn run(input: String) -> isize {
-    let (_, data) = parse(&input).unwrap();
-    dbg!(&data);
+/// The tiles enclosed by the loop.
+pub fn tiles_inside(data: &Data, loop_edges: &[Coord], loop_set: &BTreeSet<Coord>) -> Vec<Coord> {
+    data.grid
+        .iter()
+        .map(|c| c.0)
+        .filter(|c| !loop_set.contains(c))
+        // Off the loop, so never on the boundary
+        .filter(|c| winding_number(loop_edges, *c) != 0)
+        .collect()
+}
 
-    let start = data.grid.iter().find(|x| x.1 == &'S').unwrap();
+/// The loop with its pipes, the tiles inside as `I` and the other tiles as
+/// `O`, like in the puzzle.
+pub fn loop_map(data: &Data) -> TextMap {
+    let (loop_edges, loop_set) = walk_loop(data);
+    let tile = |c: Coord, v| (c.x as usize, c.y as usize, v);
+    TextMap::new(data.grid.width(), data.grid.height())
+        .layer(data.grid.iter().map(|(c, _)| tile(c, 'O')))
+        .layer(
+            tiles_inside(data, &loop_edges, &loop_set)
+                .into_iter()
+                .map(|c| tile(c, 'I')),
+        )
+        .layer(loop_set.iter().map(|c| tile(*c, data.grid[*c])))
+}
 
-    let mut current_pos = (*start.0, *start.1);
-    let mut previous_pos = current_pos;
-    let mut iterations = 0;
-    loop {
-        let new_pos = data.next_pos(current_pos.0, previous_pos.0);
-        previous_pos = current_pos;
-        current_pos = new_pos;
+pub struct Day10;
 
-        iterations += 1;
+impl Solution for Day10 {
+    const DAY: u8 = 10;
 
-        if current_pos.1 == 'S' {
-            break;
-        }
-    }
-    dbg!(&iterations / 2)
-}
+    type Model = Data;
+    type Answer1 = isize;
+    type Answer2 = usize;
 
-fn main() {
-    let input = read_input(None);
+    fn parse(input: &str) -> Result<Data, ParseError> {
+        parse_input(input, parse)
+    }
 
-    let answer = run(input);
+    fn part1(data: &Data) -> isize {
+        let start = data.grid.position(|c| *c == 'S').unwrap();
+        let search = bfs(
+            [start],
+            |pos| {
+                data.get_neighbours_with_pipes(*pos)
+                    .into_iter()
+                    .map(|n| n.0)
+            },
+            |_| false,
+        );
+        stats::record("loop_length", search.distances.len());
+        // Both ways around the loop meet at the farthest tile
+        *search.distances.values().max().unwrap() as isize
+    }
 
-    println!("Answer: {}", answer);
+    fn part2(data: &Data) -> usize {
+        let (loop_edges, loop_set) = walk_loop(data);
+        trace_map("Loop and tiles inside:", || loop_map(data));
+        tiles_inside(data, &loop_edges, &loop_set).len()
+    }
 }
 
 #[allow(unused_imports)]
@@ -358,34 +392,45 @@ mod tests {
     }
 
     #[test]
-    fn test_run1() {
-        let input = read_input(Some(indoc!(
-            "
-            -L|F7
-            7S-7|
-            L|7||
-            -L-J|
-            L|-JF
-            "
-        )));
-        dbg!(&input);
-        let answer = run(input);
-        assert_eq!(answer, 4);
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day10>();
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day10::parse(".S-7\n.|.x\n").unwrap_err(),
+            ParseError::new(
+                2,
+                4,
+                ".|.x",
+                "a pipe | - L J 7 F, the ground . or the start S"
+            )
+        );
+        assert_eq!(
+            Day10::parse(".F-7\n.L-J\n").unwrap_err(),
+            ParseError::new(1, 1, ".F-7", "a start S in the map")
+        );
+        assert_eq!(
+            Day10::parse(".S-7\n.L-S\n").unwrap_err(),
+            ParseError::new(2, 4, ".L-S", "a single start S")
+        );
+        let broken = "a pipe leading on, the loop to go back to the start S";
+        assert_eq!(
+            Day10::parse("S\n").unwrap_err(),
+            ParseError::new(1, 1, "S", broken)
+        );
+        assert_eq!(
+            Day10::parse(".S-7\n.|.|\n.L-.\n").unwrap_err(),
+            ParseError::new(2, 4, ".|.|", broken)
+        );
     }
 
     #[test]
-    fn test_run2() {
-        let input = read_input(Some(indoc!(
-            "
-            7-F7-
-            .FJ|7
-            SJLL7
-            |F--J
-            LJ.LJ
-            "
-        )));
-        dbg!(&input);
-        let answer = run(input);
-        assert_eq!(answer, 8);
+    fn test_loop_map() {
+        let data = Day10::parse(include_str!("../fixtures/example_3.txt")).unwrap();
+        aoc_common::assert_snapshot!("loop_example_3", loop_map(&data));
+        let data = Day10::parse(include_str!("../fixtures/example_5.txt")).unwrap();
+        aoc_common::assert_snapshot!("loop_example_5", loop_map(&data));
     }
 }
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_10::Day10;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day10>()
+}
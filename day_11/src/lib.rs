@@ -0,0 +1,164 @@
+//! Day 11: Cosmic Expansion
+//!
+//! The model is the image of the universe, [`total_distance`] solves both
+//! parts for any expansion.
+
+use itertools::Itertools;
+
+use aoc_common::{
+    checked,
+    grid::char_grid,
+    params::{self, Param},
+    parse::{parse_input, IResult, ParseError},
+    trace_map, Grid, Solution, TextMap,
+};
+
+fn parse(input: &str) -> IResult<&str, Data> {
+    let (input, grid) = char_grid(input)?;
+    Ok((input, Data { grid }))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Data {
+    pub grid: Grid<char>,
+}
+
+fn distance(x1: usize, y1: usize, x2: usize, y2: usize) -> usize {
+    log::trace!("{} {} {} {}", x1, y1, x2, y2);
+    let dx = (x2 as isize - x1 as isize).abs();
+    let dy = (y2 as isize - y1 as isize).abs();
+    // let dist_square = (dx.pow(2) + dy.pow(2)) as f64;
+    // dist_square.sqrt() as usize
+    (dx + dy) as usize
+}
+
+/// Sum of the distances between galaxies once every empty row and column has
+/// been replaced by `expansion` of them.
+pub fn total_distance(data: &Data, expansion: usize) -> usize {
+    log::debug!("data = {:?}", data);
+
+    let insert_row_indices = data
+        .grid
+        .rows()
+        .enumerate()
+        .filter_map(|y| {
+            if y.1.iter().all(|x| *x == '.') {
+                Some(y.0)
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<usize>>();
+
+    log::debug!("insert_row_indices = {:?}", insert_row_indices);
+
+    let insert_col_indices = (0..data.grid.width())
+        .filter(|x| data.grid.column(*x).all(|c| *c == '.'))
+        .collect::<Vec<usize>>();
+
+    log::debug!("insert_col_indices = {:?}", insert_col_indices);
+
+    trace_map("Map", || {
+        TextMap::new(data.grid.width(), data.grid.height()).layer(
+            data.grid
+                .iter()
+                .map(|(c, v)| (c.x as usize, c.y as usize, *v)),
+        )
+    });
+
+    let mut galaxies = Vec::new();
+    for y in data.grid.rows().enumerate() {
+        for x in y.1.iter().enumerate() {
+            if *x.1 == '#' {
+                // galaxies.push((x.0, y.0))
+
+                let xexp = [0]
+                    .iter()
+                    .chain(insert_col_indices.iter())
+                    .chain([data.grid.width()].iter())
+                    .cloned()
+                    .collect::<Vec<usize>>()
+                    .windows(2)
+                    .map(|i| i[0]..i[1])
+                    .enumerate()
+                    .find(|r| r.1.contains(&x.0))
+                    .map(|(i, _r)| checked::add(checked::mul(i, expansion - 1), x.0))
+                    .unwrap();
+
+                let yexp = [0]
+                    .iter()
+                    .chain(insert_row_indices.iter())
+                    .chain([data.grid.height()].iter())
+                    .cloned()
+                    .collect::<Vec<usize>>()
+                    .windows(2)
+                    .map(|i| i[0]..i[1])
+                    .enumerate()
+                    .find(|r| r.1.contains(&y.0))
+                    .map(|(i, _r)| checked::add(checked::mul(i, expansion - 1), y.0))
+                    .unwrap();
+
+                galaxies.push((xexp, yexp))
+            }
+        }
+    }
+
+    log::debug!("galaxies = {:?}", galaxies);
+
+    let travels = checked::sum(
+        galaxies
+            .iter()
+            .combinations(2)
+            .map(|t| distance(t[0].0, t[0].1, t[1].0, t[1].1)),
+    );
+
+    travels
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "expansion",
+        default: "1000000",
+        doc: "How many rows or columns an empty one becomes in part 2",
+        check: params::at_least::<1>,
+    }];
+
+    type Model = Data;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Data, ParseError> {
+        parse_input(input, parse)
+    }
+
+    fn part1(data: &Data) -> usize {
+        total_distance(data, 2)
+    }
+
+    fn part2(data: &Data) -> usize {
+        let expansion = params::get::<Self, usize>("expansion").expect("checked when loaded");
+        total_distance(data, expansion)
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day11>();
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_11::Day11;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day11>()
+}
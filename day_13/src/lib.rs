@@ -0,0 +1,179 @@
+//! Day 13: Point of Incidence
+//!
+//! The model holds the patterns. [`find_symetry_axis`] and [`find_smudge`]
+//! find the reflection lines of one pattern, without and with a smudge.
+
+use nom::{character::complete::line_ending, multi::separated_list1};
+
+use aoc_common::{
+    grid::char_grid,
+    parse::{parse_input, IResult, ParseError},
+    Grid, Solution,
+};
+
+fn parse(input: &str) -> IResult<&str, Data> {
+    let (input, patterns) = separated_list1(line_ending, char_grid)(input)?;
+
+    let data = Data { patterns };
+
+    Ok((input, data))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Data {
+    pub patterns: Vec<Grid<char>>,
+}
+
+/// Whether a reflection line is between two columns (`X`) or two rows (`Y`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymAxis {
+    X,
+    Y,
+}
+
+type Finder = fn(&Grid<char>, SymAxis) -> Vec<(SymAxis, usize)>;
+
+/// Sum the reflection lines found by `finder` in every pattern.
+fn summarize(data: &Data, finder: Finder) -> usize {
+    log::debug!("data = {:?}", data);
+
+    let sym_y = data
+        .patterns
+        .iter()
+        .map(|p| finder(p, SymAxis::Y))
+        .collect::<Vec<_>>();
+
+    let sym_x = data
+        .patterns
+        .iter()
+        .map(|p| finder(&p.transpose(), SymAxis::X))
+        .collect::<Vec<_>>();
+
+    log::debug!("sym_x = {:?}", sym_x);
+    log::debug!("sym_y = {:?}", sym_y);
+
+    let output = data
+        .patterns
+        .iter()
+        .enumerate()
+        .flat_map(|(i, _p)| {
+            let mut merged_axis = sym_y[i].clone();
+            merged_axis.append(&mut sym_x[i].clone());
+            merged_axis
+        })
+        .map(|(axis, i)| match axis {
+            SymAxis::X => i + 1,
+            SymAxis::Y => (i + 1) * 100,
+        })
+        .collect::<Vec<_>>();
+
+    output.iter().sum::<usize>()
+}
+
+/// The rows after which `pattern` is reflected, labelled with `axis`.
+pub fn find_symetry_axis(pattern: &Grid<char>, axis: SymAxis) -> Vec<(SymAxis, usize)> {
+    let p = pattern.rows().collect::<Vec<&[char]>>();
+    p.windows(2)
+        .enumerate()
+        .filter_map(|y| {
+            if y.1[0] == y.1[1] {
+                let before = (0..=y.0)
+                    .rev()
+                    .flat_map(|i| p[i].to_vec())
+                    .collect::<Vec<_>>();
+                let after = ((y.0 + 1)..p.len())
+                    .flat_map(|i| p[i].to_vec())
+                    .collect::<Vec<_>>();
+
+                let length = match before.len() < after.len() {
+                    true => before.len(),
+                    false => after.len(),
+                };
+
+                if before[..length] == after[..length] {
+                    Some((axis, y.0))
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>()
+}
+
+/// The rows after which `pattern` would be reflected with exactly one tile
+/// changed, labelled with `axis`.
+pub fn find_smudge(pattern: &Grid<char>, axis: SymAxis) -> Vec<(SymAxis, usize)> {
+    let p = pattern.rows().collect::<Vec<&[char]>>();
+    p.iter()
+        .enumerate()
+        .filter_map(|y| {
+            let before = (0..=y.0)
+                .rev()
+                .flat_map(|i| p[i].to_vec())
+                .collect::<Vec<_>>();
+            let after = ((y.0 + 1)..p.len())
+                .flat_map(|i| p[i].to_vec())
+                .collect::<Vec<_>>();
+
+            let length = match before.len() < after.len() {
+                true => before.len(),
+                false => after.len(),
+            };
+
+            let diff = before[..length]
+                .iter()
+                .zip(after[..length].iter())
+                .filter(|t| t.0 != t.1)
+                .collect::<Vec<_>>();
+
+            if diff.len() == 1 {
+                Some((axis, y.0))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Model = Data;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Data, ParseError> {
+        parse_input(input, parse)
+    }
+
+    fn part1(data: &Data) -> usize {
+        summarize(data, find_symetry_axis)
+    }
+
+    fn part2(data: &Data) -> usize {
+        summarize(data, find_smudge)
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day13>();
+    }
+}
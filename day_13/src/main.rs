@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_13::Day13;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day13>()
+}
@@ -0,0 +1,396 @@
+//! Day 14: Parabolic Reflector Dish
+//!
+//! The model is the platform. [`tilt_cycle`] runs one spin cycle on it and
+//! [`north_load`] weighs the result.
+
+use core::panic;
+use itertools::Itertools;
+use nom::error::context;
+use std::collections::HashMap;
+
+use aoc_common::{
+    get_neighbours,
+    grid::char_grid_of,
+    params::{self, Param},
+    parse::{parse_input, IResult, ParseError},
+    render::{Frames, Image, Palette},
+    simulation::{Simulate, Simulation},
+    stats, trace_map, Coord, Grid, Solution, TextMap,
+};
+
+fn parse(input: &str) -> IResult<&str, Data> {
+    let (input, grid) = context(
+        "a rounded rock O, a cube-shaped rock # or an empty space .",
+        char_grid_of("O#."),
+    )(input)?;
+    Ok((input, Data { grid }))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Data {
+    pub grid: Grid<char>,
+}
+
+impl Data {
+    // Get all neighbours clockwise
+    fn get_neighbours(&self, coord: Coord) -> Vec<Option<(Coord, char)>> {
+        get_neighbours(&self.grid, coord)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TiltDirection {
+    East = 0,
+    South = 1,
+    West = 2,
+    North = 3,
+}
+
+fn spin_cycles(data: &mut Data, cycles: usize) {
+    // The platform after each number of cycles, and after how many it was
+    // first seen
+    let mut states = vec![data.grid.clone()];
+    let mut seen = HashMap::from([(data.grid.clone(), 0)]);
+    let mut frames = Frames::from_env("day_14");
+
+    for done in 1..=cycles {
+        tilt_cycle(data);
+        if let Some(frames) = frames.as_mut() {
+            frames.record(&Image::new(&data.grid, &Palette::default()));
+        }
+        if let Some(&start) = seen.get(&data.grid) {
+            let cycle_length = done - start;
+            stats::record("cycle_start", start);
+            stats::record("cycle_length", cycle_length);
+            // From `start` on the platform goes through the same
+            // `cycle_length` states again and again, the one after `cycles`
+            // cycles is one of them.
+            data.grid = states[start + (cycles - start) % cycle_length].clone();
+            return;
+        }
+        seen.insert(data.grid.clone(), done);
+        states.push(data.grid.clone());
+    }
+}
+
+/// The platform, its rounded rocks `O` and cube-shaped rocks `#`.
+pub fn platform_map(data: &Data) -> TextMap {
+    TextMap::new(data.grid.width(), data.grid.height()).layer(
+        data.grid
+            .iter()
+            .map(|(c, v)| (c.x as usize, c.y as usize, *v)),
+    )
+}
+
+/// Total load on the north support beams.
+pub fn north_load(data: &Data) -> usize {
+    trace_map("Platform:", || platform_map(data));
+
+    data.grid
+        .rows()
+        .enumerate()
+        .map(|(y, row)| (data.grid.height() - y) * row.iter().filter(|c| **c == 'O').count())
+        .sum()
+}
+
+/// Tilt the platform north, west, south then east.
+pub fn tilt_cycle(data: &mut Data) {
+    tilt(&find_motif(&*data, 'O'), data, &TiltDirection::North);
+    tilt(&find_motif(&*data, 'O'), data, &TiltDirection::West);
+    tilt(&find_motif(&*data, 'O'), data, &TiltDirection::South);
+    tilt(&find_motif(&*data, 'O'), data, &TiltDirection::East);
+}
+
+fn tilt(rrocks: &[Coord], data: &mut Data, direction: &TiltDirection) {
+    let sort_type = match direction {
+        TiltDirection::East => east_sort(rrocks),
+        TiltDirection::South => south_sort(rrocks),
+        TiltDirection::West => west_sort(rrocks),
+        TiltDirection::North => north_sort(rrocks),
+    };
+    for rcoord in sort_type.iter() {
+        for t_rcoord in translation_coord(rcoord, data, direction) {
+            let neighbours = data.get_neighbours(t_rcoord);
+            if let Some((nc, v)) = neighbours.get(*direction as usize).unwrap() {
+                match v {
+                    '.' => {
+                        // *c = *nc;
+                        data.grid[t_rcoord] = '.';
+                        data.grid[*nc] = 'O';
+                    }
+                    '#' => {
+                        break;
+                    }
+                    'O' => {
+                        break;
+                    }
+                    _ => panic!("Non expected char"),
+                }
+            }
+        }
+    }
+}
+
+fn translation_coord(rcoord: &Coord, data: &mut Data, direction: &TiltDirection) -> Vec<Coord> {
+    match direction {
+        TiltDirection::East => (rcoord.x..data.grid.width() as isize)
+            .map(|x| Coord::from((x, rcoord.y)))
+            .collect(),
+        TiltDirection::South => (rcoord.y..data.grid.height() as isize)
+            .map(|y| Coord::from((rcoord.x, y)))
+            .collect(),
+        TiltDirection::West => ((0..=rcoord.x).rev())
+            .map(|x| Coord::from((x, rcoord.y)))
+            .collect(),
+        TiltDirection::North => ((0..=rcoord.y).rev())
+            .map(|y| Coord::from((rcoord.x, y)))
+            .collect(),
+    }
+}
+
+fn west_sort(rrocks: &[Coord]) -> Vec<Coord> {
+    rrocks
+        .iter()
+        .sorted_by(|v1, v2| {
+            if v1.x == v2.x {
+                v2.y.cmp(&v1.y)
+            } else {
+                v1.x.cmp(&v2.x)
+            }
+        })
+        .copied()
+        .collect::<Vec<Coord>>()
+}
+
+fn south_sort(rrocks: &[Coord]) -> Vec<Coord> {
+    rrocks
+        .iter()
+        .sorted_by(|v1, v2| {
+            if v1.x == v2.x {
+                v2.y.cmp(&v1.y)
+            } else {
+                v1.x.cmp(&v2.x)
+            }
+        })
+        .copied()
+        .collect::<Vec<Coord>>()
+}
+
+fn east_sort(rrocks: &[Coord]) -> Vec<Coord> {
+    rrocks
+        .iter()
+        .sorted_by(|v1, v2| {
+            if v1.x == v2.x {
+                v1.y.cmp(&v2.y)
+            } else {
+                v2.x.cmp(&v1.x)
+            }
+        })
+        .copied()
+        .collect::<Vec<Coord>>()
+}
+
+fn north_sort(rrocks: &[Coord]) -> Vec<Coord> {
+    rrocks
+        .iter()
+        .sorted_by(|v1, v2| {
+            if v1.x == v2.x {
+                v1.y.cmp(&v2.y)
+            } else {
+                v1.x.cmp(&v2.x)
+            }
+        })
+        .copied()
+        .collect::<Vec<Coord>>()
+}
+
+/// The platform tilted one direction at a time, in the order of a spin cycle.
+#[derive(Debug, Clone)]
+struct Tilting {
+    data: Data,
+    tilts: usize,
+}
+
+impl Tilting {
+    const ORDER: [TiltDirection; 4] = [
+        TiltDirection::North,
+        TiltDirection::West,
+        TiltDirection::South,
+        TiltDirection::East,
+    ];
+}
+
+impl Simulation for Tilting {
+    fn status(&self) -> String {
+        let last = match self.tilts {
+            0 => "not tilted yet".to_string(),
+            n => format!("tilted {:?}", Self::ORDER[(n - 1) % 4]),
+        };
+        format!(
+            "cycle {}, {}, north load {}",
+            self.tilts / 4,
+            last,
+            north_load(&self.data)
+        )
+    }
+
+    fn step(&mut self) -> bool {
+        let direction = Self::ORDER[self.tilts % 4];
+        tilt(&find_motif(&self.data, 'O'), &mut self.data, &direction);
+        self.tilts += 1;
+        true
+    }
+
+    fn map(&self) -> Grid<char> {
+        self.data.grid.clone()
+    }
+
+    fn inspect(&self, coord: Coord) -> Option<String> {
+        let description = match self.data.grid.get(coord)? {
+            'O' => format!(
+                "rounded rock, {} of north load",
+                self.data.grid.height() - coord.y as usize
+            ),
+            '#' => "cube rock".to_string(),
+            _ => "empty space".to_string(),
+        };
+        Some(format!("({}, {}) {}", coord.x, coord.y, description))
+    }
+}
+
+fn find_motif(data: &Data, motif: char) -> Vec<Coord> {
+    data.grid
+        .iter()
+        .filter_map(|(coord, c)| (*c == motif).then_some(coord))
+        .collect::<Vec<Coord>>()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "cycles",
+        default: "1000000000",
+        doc: "Spin cycles run in part 2",
+        check: params::at_least::<0>,
+    }];
+
+    type Model = Data;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Data, ParseError> {
+        parse_input(input, parse)
+    }
+
+    fn part1(data: &Data) -> usize {
+        log::debug!("data = {:?}", data);
+        let mut data = data.clone();
+        tilt(&find_motif(&data, 'O'), &mut data, &TiltDirection::North);
+        north_load(&data)
+    }
+
+    fn part2(data: &Data) -> usize {
+        let mut data = data.clone();
+        let cycles = params::get::<Self, usize>("cycles").expect("checked when loaded");
+        spin_cycles(&mut data, cycles);
+        north_load(&data)
+    }
+}
+
+impl Simulate for Day14 {
+    fn simulation(data: &Data) -> Box<dyn Simulation> {
+        Box::new(Tilting {
+            data: data.clone(),
+            tilts: 0,
+        })
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day14>();
+    }
+
+    #[test]
+    fn test_spin_cycles() {
+        let example = Day14::parse(include_str!("../fixtures/example_1.txt")).unwrap();
+        for cycles in [0, 1, 2, 3, 10, 11, 12, 1000] {
+            let mut spun = example.clone();
+            spin_cycles(&mut spun, cycles);
+            let mut expected = example.clone();
+            for _ in 0..cycles {
+                tilt_cycle(&mut expected);
+            }
+            assert_eq!(spun, expected, "after {} cycles", cycles);
+        }
+
+        // Platforms that never change, without round rocks
+        for input in ["#\n", ".\n", "...\n...\n"] {
+            let data = Day14::parse(input).unwrap();
+            let mut spun = data.clone();
+            spin_cycles(&mut spun, 1_000_000_000);
+            assert_eq!(spun, data);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day14::parse("O.#\nO.X\n").unwrap_err(),
+            ParseError::new(
+                2,
+                3,
+                "O.X",
+                "a rounded rock O, a cube-shaped rock # or an empty space ."
+            )
+        );
+    }
+
+    #[test]
+    fn test_platform_map() {
+        let mut data = Day14::parse(include_str!("../fixtures/example_1.txt")).unwrap();
+        tilt(&find_motif(&data, 'O'), &mut data, &TiltDirection::North);
+        aoc_common::assert_snapshot!("tilted_north", platform_map(&data));
+
+        let mut data = Day14::parse(include_str!("../fixtures/example_1.txt")).unwrap();
+        for _ in 0..3 {
+            tilt_cycle(&mut data);
+        }
+        aoc_common::assert_snapshot!("after_3_cycles", platform_map(&data));
+    }
+
+    #[test]
+    fn test_simulation() {
+        let data = Day14::parse(include_str!("../fixtures/example_1.txt")).unwrap();
+        let mut simulation = Day14::simulation(&data);
+        let mut cycled = data.clone();
+        tilt_cycle(&mut cycled);
+        for _ in 0..4 {
+            assert!(simulation.step());
+        }
+        assert_eq!(simulation.map(), cycled.grid);
+        assert_eq!(
+            simulation.status(),
+            format!("cycle 1, tilted East, north load {}", north_load(&cycled))
+        );
+        assert_eq!(
+            simulation.inspect(Coord::new(0, 9)),
+            Some("(0, 9) cube rock".to_string())
+        );
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_14::Day14;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day14>()
+}
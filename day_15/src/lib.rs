@@ -1,3 +1,8 @@
+//! Day 15: Lens Library
+//!
+//! The model is the initialization sequence, split into steps. [`hash`] is
+//! the HASH algorithm both parts are built on.
+
 use std::collections::BTreeMap;
 
 use nom::{
@@ -6,14 +11,10 @@ use nom::{
     *,
 };
 
-fn read_input(input: Option<&str>) -> String {
-    let input = match input {
-        None => include_str!("../../input.txt"),
-        Some(x) => x,
-    };
-
-    input.to_string()
-}
+use aoc_common::{
+    parse::{parse_input, IResult, ParseError},
+    Solution,
+};
 
 fn parse(input: &str) -> IResult<&str, Data> {
     let (input, steps) = separated_list1(tag(","), parse_step)(input)?;
@@ -31,8 +32,8 @@ fn parse_step(input: &str) -> IResult<&str, String> {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Data {
-    steps: Vec<String>,
+pub struct Data {
+    pub steps: Vec<String>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -133,54 +134,66 @@ impl LensBox {
     }
 }
 
-fn run(input: String) -> usize {
-    let (_, data) = parse(&input).unwrap();
-    dbg!(&data);
-
-    let lens_list = data
-        .steps
-        .iter()
-        .map(|s| match s.contains('=') {
-            true => {
-                let v = s.split('=').collect::<Vec<&str>>();
-                Lens::new(
-                    v[0].to_string(),
-                    Operation::from('='),
-                    v[1].parse().unwrap(),
-                )
-            }
-            false => {
-                let v = s.split('-').collect::<Vec<&str>>();
-                Lens::new(v[0].to_string(), Operation::from('-'), 0)
-            }
-        })
-        .collect::<Vec<_>>();
+pub struct Day15;
 
-    let mut lens_box = LensBox::new();
+impl Solution for Day15 {
+    const DAY: u8 = 15;
 
-    for lens in lens_list.into_iter() {
-        match lens.operation {
-            Operation::Add => {
-                lens_box.insert_lens(lens.hash(), lens);
-            }
-            Operation::Remove => {
-                lens_box.remove_lens(lens.hash(), lens);
-            }
-        }
+    type Model = Data;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Data, ParseError> {
+        parse_input(input, parse)
     }
 
-    dbg!(lens_box.focusing_power())
-}
+    fn part1(data: &Data) -> usize {
+        log::debug!("data = {:?}", data);
 
-fn main() {
-    let input = read_input(None);
+        data.steps.iter().map(|s| hash(s)).sum()
+    }
+
+    fn part2(data: &Data) -> usize {
+        log::debug!("data = {:?}", data);
 
-    let answer = run(input);
+        let lens_list = data
+            .steps
+            .iter()
+            .map(|s| match s.contains('=') {
+                true => {
+                    let v = s.split('=').collect::<Vec<&str>>();
+                    Lens::new(
+                        v[0].to_string(),
+                        Operation::from('='),
+                        v[1].parse().unwrap(),
+                    )
+                }
+                false => {
+                    let v = s.split('-').collect::<Vec<&str>>();
+                    Lens::new(v[0].to_string(), Operation::from('-'), 0)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut lens_box = LensBox::new();
+
+        for lens in lens_list.into_iter() {
+            match lens.operation {
+                Operation::Add => {
+                    lens_box.insert_lens(lens.hash(), lens);
+                }
+                Operation::Remove => {
+                    lens_box.remove_lens(lens.hash(), lens);
+                }
+            }
+        }
 
-    println!("Answer: {}", answer);
+        lens_box.focusing_power()
+    }
 }
 
-fn hash(s: &str) -> usize {
+/// The HASH algorithm of the puzzle.
+pub fn hash(s: &str) -> usize {
     s.chars().fold(0, |mut acc, c| {
         acc = ((acc + c as usize) * 17) % 256;
         acc
@@ -201,17 +214,12 @@ mod tests {
     }
 
     #[test]
-    fn test_hash() {
-        assert_eq!(hash("HASH"), 52);
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day15>();
     }
 
     #[test]
-    fn test_run1() {
-        let input = read_input(Some(indoc!(
-            "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"
-        )));
-        dbg!(&input);
-        let answer = run(input);
-        assert_eq!(answer, 145);
+    fn test_hash() {
+        assert_eq!(hash("HASH"), 52);
     }
 }
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_15::Day15;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day15>()
+}
@@ -1,92 +1,51 @@
-use core::panic;
-use std::{
-    collections::{HashMap, HashSet, VecDeque},
-    ops::Add,
-};
+//! Day 16: The Floor Will Be Lava
+//!
+//! The model is the contraption, [`energized_tiles`] follows a [`Beam`]
+//! through it.
 
-use nom::{
-    bytes::complete::take_until, character::complete::line_ending, multi::many1,
-    sequence::terminated, *,
-};
+use core::panic;
+use std::collections::{HashSet, VecDeque};
 
-fn read_input(input: Option<&str>) -> String {
-    let input = match input {
-        None => include_str!("../../input.txt"),
-        Some(x) => x,
-    };
+use nom::error::context;
 
-    input.to_string()
-}
+use aoc_common::{
+    get_neighbours,
+    grid::char_grid_of,
+    parse::{parse_input, IResult, ParseError},
+    render::{Frames, Image, Palette, Rgb},
+    simulation::{Simulate, Simulation},
+    trace_map, Coord, Direction, Grid, Solution, TextMap,
+};
 
 fn parse(input: &str) -> IResult<&str, Data> {
-    let (input, lines) = many1(parse_line)(input)?;
-
-    let data = lines
-        .iter()
-        .enumerate()
-        .flat_map(|y| {
-            y.1.iter()
-                .enumerate()
-                .map(|x| (Coord::from((x.0 as isize, y.0 as isize)), *x.1))
-                .collect::<Vec<(Coord, char)>>()
-        })
-        .collect::<HashMap<Coord, char>>();
-
-    let length_x = lines[0].len();
-    let length_y = lines.len();
-
-    let data = Data {
-        length_x,
-        length_y,
-        grid: data,
-    };
-
-    Ok((input, data))
-}
-
-fn parse_line(input: &str) -> IResult<&str, Vec<char>> {
-    let (input, pipe) = terminated(take_until("\n"), line_ending)(input)?;
-    let pipe = pipe.to_string().chars().collect();
-    Ok((input, pipe))
+    let (input, grid) = context(
+        "an empty space ., a mirror / or \\ or a splitter | or -",
+        char_grid_of(".\\/|-"),
+    )(input)?;
+    Ok((input, Data { grid }))
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct Data {
-    length_x: usize,
-    length_y: usize,
-    grid: HashMap<Coord, char>,
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Data {
+    pub grid: Grid<char>,
 }
 
 impl Data {
     // Get all neighbours clockwise
     fn get_neighbours(&self, coord: Coord) -> Vec<Option<(Coord, char)>> {
-        let neighbours_coords: Vec<Coord> =
-            Vec::from([(1, 0).into(), (0, 1).into(), (-1, 0).into(), (0, -1).into()]);
-
-        let neighbours = neighbours_coords
-            .iter()
-            .map(|nc| self.grid.get(&(coord + *nc)).map(|v| (coord + *nc, *v)))
-            .collect::<Vec<Option<(Coord, char)>>>();
-        neighbours
+        get_neighbours(&self.grid, coord)
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
-enum Direction {
-    Right = 0,
-    Down = 1,
-    Left = 2,
-    Up = 3,
-}
-
+/// A beam of light on its way, `pos` being the last tile it reached.
 #[derive(Debug, Eq, PartialEq, Clone)]
-struct Beam {
-    direction: Direction,
-    pos: Coord,
+pub struct Beam {
+    pub direction: Direction,
+    pub pos: Coord,
 }
 
 impl Beam {
-    fn new(direction: Direction, pos: Coord) -> Self {
+    pub fn new(direction: Direction, pos: Coord) -> Self {
         Self { direction, pos }
     }
 
@@ -319,119 +278,193 @@ impl Beam {
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy, Ord, PartialOrd, Hash)]
-struct Coord {
-    x: isize,
-    y: isize,
-}
-
-impl From<(isize, isize)> for Coord {
-    fn from((x, y): (isize, isize)) -> Self {
-        Self { x, y }
-    }
+/// The beams moving through the contraption, all of them one tile per step.
+#[derive(Debug, Clone)]
+struct Beams {
+    data: Data,
+    beams: VecDeque<Beam>,
+    energized_tiles: HashSet<Coord>,
+    seen: HashSet<(Coord, Direction)>,
+    steps: usize,
 }
 
-impl Add<Coord> for Coord {
-    type Output = Coord;
-    fn add(self, rhs: Coord) -> Self::Output {
+impl Beams {
+    fn new(beam: Beam, data: &Data) -> Self {
         Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
+            data: data.clone(),
+            beams: VecDeque::from([beam]),
+            energized_tiles: HashSet::new(),
+            seen: HashSet::new(),
+            steps: 0,
         }
     }
-}
 
-fn run(input: String) -> usize {
-    let (_, data) = parse(&input).unwrap();
-    // dbg!(&data);
+    fn image(&self) -> Image {
+        Image::new(&self.data.grid, &Palette::default())
+            .highlight(self.energized_tiles.iter().copied(), Rgb::YELLOW)
+            .highlight(self.beams.iter().map(|b| b.pos), Rgb::RED)
+    }
+}
 
-    print_text_map(
-        &data
-            .grid
-            .iter()
-            .map(|c| (c.0.x as usize, c.0.y as usize, *c.1))
-            .collect::<Vec<(usize, usize, char)>>(),
-        data.length_x,
-        data.length_y,
-    );
-
-    let left_tiles =
-        (0..data.length_y).map(|r| Beam::new(Direction::Right, Coord::from((0isize, r as isize))));
-    let right_tiles = (0..data.length_y).map(|r| {
-        Beam::new(
-            Direction::Left,
-            Coord::from((data.length_x as isize - 1, r as isize)),
-        )
-    });
-    let up_tiles =
-        (0..data.length_x).map(|c| Beam::new(Direction::Down, Coord::from((c as isize, 0isize))));
-    let down_tiles = (0..data.length_x).map(|c| {
-        Beam::new(
-            Direction::Up,
-            Coord::from((c as isize, data.length_y as isize - 1)),
+impl Simulation for Beams {
+    fn status(&self) -> String {
+        format!(
+            "step {}, {} beams, {} tiles energized",
+            self.steps,
+            self.beams.len(),
+            self.energized_tiles.len()
         )
-    });
-
-    let et: Vec<usize> = left_tiles
-        .chain(right_tiles)
-        .chain(up_tiles)
-        .chain(down_tiles)
-        .map(|b| {
-            let mut beams: VecDeque<Beam> = VecDeque::new();
-            beams.push_back(b.clone());
-            find_energized_tiles(beams, &data)
-        })
-        .collect();
-
-    dbg!(*et.iter().max().unwrap())
-}
+    }
 
-fn find_energized_tiles(mut beams: VecDeque<Beam>, data: &Data) -> usize {
-    let mut energized_tiles: HashSet<Coord> = HashSet::new();
-    let mut seen: HashSet<(Coord, Direction)> = HashSet::new();
-
-    // We need to mark the first tile because it is in the grid.
-    energized_tiles.insert(beams[0].pos);
-
-    while !beams.is_empty() {
-        if let Some(new_beams) =
-            beams
-                .pop_front()
-                .unwrap()
-                .advence(data, &mut energized_tiles, &mut seen)
-        {
-            new_beams.iter().for_each(|b| beams.push_back(b.clone()));
+    fn step(&mut self) -> bool {
+        if self.beams.is_empty() {
+            return false;
+        }
+        for _ in 0..self.beams.len() {
+            let mut beam = self.beams.pop_front().unwrap();
+            if let Some(new_beams) =
+                beam.advence(&self.data, &mut self.energized_tiles, &mut self.seen)
+            {
+                self.beams.extend(new_beams);
+            }
         }
+        self.steps += 1;
+        true
     }
 
-    energized_tiles.len()
-}
+    fn map(&self) -> Grid<char> {
+        self.data.grid.clone()
+    }
 
-fn print_text_map(coordinates: &[(usize, usize, char)], width: usize, height: usize) {
-    let mut grid = vec![vec!['.'; width]; height];
+    fn highlights(&self) -> Vec<(Coord, Rgb)> {
+        self.energized_tiles
+            .iter()
+            .map(|c| (*c, Rgb::YELLOW))
+            .chain(self.beams.iter().map(|b| (b.pos, Rgb::RED)))
+            .collect()
+    }
 
-    // Place the points on the grid
-    for &(x, y, v) in coordinates {
-        if x < width && y < height {
-            grid[y][x] = v; // Assuming the origin (0,0) is at the top-left corner
+    fn inspect(&self, coord: Coord) -> Option<String> {
+        let tile = match self.data.grid.get(coord)? {
+            '.' => "empty space".to_string(),
+            '|' | '-' => format!("splitter {}", self.data.grid[coord]),
+            c => format!("mirror {}", c),
+        };
+        let mut description = format!("({}, {}) {}", coord.x, coord.y, tile);
+        if self.energized_tiles.contains(&coord) {
+            description.push_str(", energized");
         }
+        let directions = self
+            .beams
+            .iter()
+            .filter(|b| b.pos == coord)
+            .map(|b| format!("{:?}", b.direction))
+            .collect::<Vec<_>>();
+        if !directions.is_empty() {
+            description.push_str(&format!(", beams going {}", directions.join(", ")));
+        }
+        Some(description)
     }
+}
 
-    // Print the grid row by row
-    for row in grid {
-        for cell in row {
-            print!("{}", cell);
+/// All the tiles crossed by `beam` and the beams it splits into. With
+/// `frames`, one frame is written each time every beam has moved one tile.
+pub fn energized_tiles(beam: Beam, data: &Data, mut frames: Option<&mut Frames>) -> HashSet<Coord> {
+    let mut beams = Beams::new(beam, data);
+    loop {
+        if let Some(frames) = frames.as_mut() {
+            frames.record(&beams.image());
+        }
+        if !beams.step() {
+            break;
         }
-        println!(); // Newline at the end of each row
     }
+    beams.energized_tiles
+}
+
+/// The energized tiles as `#`, under the mirrors and splitters of the
+/// contraption.
+pub fn energized_map(data: &Data, energized_tiles: &HashSet<Coord>) -> TextMap {
+    TextMap::new(data.grid.width(), data.grid.height())
+        .layer(
+            energized_tiles
+                .iter()
+                .map(|c| (c.x as usize, c.y as usize, '#')),
+        )
+        .layer(
+            data.grid
+                .iter()
+                .filter(|(_, v)| **v != '.')
+                .map(|(c, v)| (c.x as usize, c.y as usize, *v)),
+        )
 }
 
-fn main() {
-    let input = read_input(None);
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Model = Data;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Data, ParseError> {
+        parse_input(input, parse)
+    }
+
+    fn part1(data: &Data) -> usize {
+        let energized_tiles = energized_tiles(
+            Beam::new(Direction::Right, Coord::from((-1, 0))),
+            data,
+            Frames::from_env("day_16").as_mut(),
+        );
+
+        trace_map("Energized tiles:", || energized_map(data, &energized_tiles));
 
-    let answer = run(input);
+        energized_tiles.len()
+    }
 
-    println!("Answer: {}", answer);
+    fn part2(data: &Data) -> usize {
+        let left_tiles = (0..data.grid.height())
+            .map(|r| Beam::new(Direction::Right, Coord::from((0isize, r as isize))));
+        let right_tiles = (0..data.grid.height()).map(|r| {
+            Beam::new(
+                Direction::Left,
+                Coord::from((data.grid.width() as isize - 1, r as isize)),
+            )
+        });
+        let up_tiles = (0..data.grid.width())
+            .map(|c| Beam::new(Direction::Down, Coord::from((c as isize, 0isize))));
+        let down_tiles = (0..data.grid.width()).map(|c| {
+            Beam::new(
+                Direction::Up,
+                Coord::from((c as isize, data.grid.height() as isize - 1)),
+            )
+        });
+
+        left_tiles
+            .chain(right_tiles)
+            .chain(up_tiles)
+            .chain(down_tiles)
+            .map(|b| {
+                let start = b.pos;
+                let mut energized_tiles = energized_tiles(b, data, None);
+                // We need to mark the first tile because it is in the grid.
+                energized_tiles.insert(start);
+                energized_tiles.len()
+            })
+            .max()
+            .unwrap()
+    }
+}
+
+impl Simulate for Day16 {
+    fn simulation(data: &Data) -> Box<dyn Simulation> {
+        Box::new(Beams::new(
+            Beam::new(Direction::Right, Coord::from((-1, 0))),
+            data,
+        ))
+    }
 }
 
 #[allow(unused_imports)]
@@ -448,76 +481,50 @@ mod tests {
         assert_eq!(1, 1);
     }
 
-    #[rstest]
-    // ...
-    // .>.
-    // ...
-    #[case(Direction::Right, "..\n..\n..\n", None)]
-    #[case(Direction::Right, "...\n..|\n...\n", Some(vec![Beam::new(Direction::Up, Coord::from((2,1))),
-                                                          Beam::new(Direction::Down, Coord::from((2,1)))]
-    ))]
-    #[case(Direction::Right,"...\n..-\n...\n", Some(vec![Beam::new(Direction::Right, Coord::from((2,1)))]))]
-    #[case(Direction::Right,"...\n../\n...\n", Some(vec![Beam::new(Direction::Up, Coord::from((2,1)))]))]
-    #[case(Direction::Right,"...\n..\\n...\n", Some(vec![Beam::new(Direction::Down, Coord::from((2,1)))]))]
-    #[case(Direction::Right,"...\n...\n...\n", Some(vec![Beam::new(Direction::Right, Coord::from((2,1)))]))]
-    #[case(Direction::Up,".-.\n...\n...\n", Some(vec![Beam::new(Direction::Right, Coord::from((1,0))),
-                                                      Beam::new(Direction::Left, Coord::from((1,0)))]
-    ))]
-    #[case(Direction::Up,".|.\n...\n...\n", Some(vec![Beam::new(Direction::Up, Coord::from((1,0)))]))]
-    #[case(Direction::Up,"./.\n...\n...\n", Some(vec![Beam::new(Direction::Right, Coord::from((1,0)))]))]
-    #[case(Direction::Up,".\\.\n...\n...\n", Some(vec![Beam::new(Direction::Left, Coord::from((1,0)))]))]
-    #[case(Direction::Up,"...\n...\n...\n", Some(vec![Beam::new(Direction::Up, Coord::from((1,0)))]))]
-    #[case(Direction::Down,"...\n...\n.-.\n", Some(vec![Beam::new(Direction::Right, Coord::from((1,2))),
-                                                        Beam::new(Direction::Left, Coord::from((1,2)))]
-    ))]
-    #[case(Direction::Down,"...\n...\n.|.\n", Some(vec![Beam::new(Direction::Down, Coord::from((1,2)))]))]
-    #[case(Direction::Down,"...\n...\n./.\n", Some(vec![Beam::new(Direction::Left, Coord::from((1,2)))]))]
-    #[case(Direction::Down,"...\n...\n.\\.\n", Some(vec![Beam::new(Direction::Right, Coord::from((1,2)))]))]
-    #[case(Direction::Down,"...\n...\n...\n", Some(vec![Beam::new(Direction::Down, Coord::from((1,2)))]))]
-    #[case(Direction::Left,"...\n|..\n...\n", Some(vec![Beam::new(Direction::Up, Coord::from((0,1))),
-                                                         Beam::new(Direction::Down, Coord::from((0,1)))]
-    ))]
-    #[case(Direction::Left,"...\n-..\n...\n", Some(vec![Beam::new(Direction::Left, Coord::from((0,1)))]))]
-    #[case(Direction::Left,"...\n/..\n...\n", Some(vec![Beam::new(Direction::Down, Coord::from((0,1)))]))]
-    #[case(Direction::Left,"...\n\\..\n...\n", Some(vec![Beam::new(Direction::Up, Coord::from((0,1)))]))]
-    #[case(Direction::Left,"...\n...\n...\n", Some(vec![Beam::new(Direction::Left, Coord::from((0,1)))]))]
-    fn test_advance(
-        #[case] d: Direction,
-        #[case] input: &str,
-        #[case] expected: Option<Vec<Beam>>,
-    ) {
-        // let input = read_input(Some(s));
-        let (_, data) = parse(input).unwrap();
-        let mut beams: VecDeque<Beam> = VecDeque::new();
-        beams.push_back(Beam::new(d, Coord::from((1, 1))));
-        let mut energized_tiles: HashSet<Coord> = HashSet::new();
-        let mut seen: HashSet<(Coord, Direction)> = HashSet::new();
-
-        let new_beams = beams
-            .pop_front()
-            .unwrap()
-            .advence(&data, &mut energized_tiles, &mut seen);
-        assert_eq!(new_beams, expected);
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day16>();
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day16::parse("./\\\n.X.\n").unwrap_err(),
+            ParseError::new(
+                2,
+                2,
+                ".X.",
+                "an empty space ., a mirror / or \\ or a splitter | or -"
+            )
+        );
+    }
+
+    #[test]
+    fn test_energized_map() {
+        let data = Day16::parse(include_str!("../fixtures/example_1.txt")).unwrap();
+        let energized =
+            energized_tiles(Beam::new(Direction::Right, Coord::new(-1, 0)), &data, None);
+        aoc_common::assert_snapshot!("energized_example_1", energized_map(&data, &energized));
     }
 
     #[test]
-    fn test_run1() {
-        let input = read_input(Some(indoc!(
-            r"
-            .|...\....
-            |.-.\.....
-            .....|-...
-            ........|.
-            ..........
-            .........\
-            ..../.\\..
-            .-.-/..|..
-            .|....-|.\
-            ..//.|....
-            "
-        )));
-        dbg!(&input);
-        let answer = run(input);
-        assert_eq!(answer, 51);
+    fn test_simulation() {
+        let data = Day16::parse(include_str!("../fixtures/example_1.txt")).unwrap();
+        let mut simulation = Day16::simulation(&data);
+        assert!(simulation.step());
+        assert_eq!(
+            simulation.inspect(Coord::new(0, 0)),
+            Some("(0, 0) empty space, energized, beams going Right".to_string())
+        );
+        assert!(simulation.step());
+        assert_eq!(
+            simulation.inspect(Coord::new(1, 0)),
+            Some("(1, 0) splitter |, energized, beams going Up, Down".to_string())
+        );
+        while simulation.step() {}
+        assert!(simulation
+            .status()
+            .ends_with(", 0 beams, 46 tiles energized"));
+        assert_eq!(simulation.highlights().len(), 46);
     }
 }
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_16::Day16;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day16>()
+}
@@ -1,107 +1,41 @@
-use std::{
-    collections::{HashMap, HashSet, VecDeque},
-    isize,
-    ops::Add,
+//! Day 17: Clumsy Crucible
+//!
+//! The model is the map of heat loss per block, [`min_heat_loss`] finds the
+//! best path for a crucible with the given [`Moves`].
+
+use nom::error::context;
+
+use aoc_common::{
+    get_neighbours,
+    grid::char_grid_of,
+    params::{self, Param, Values},
+    parse::{parse_input, IResult, ParseError},
+    search::dijkstra,
+    solution::Maybe,
+    stats, Coord, Direction, Grid, Solution,
 };
 
-use nom::{
-    bytes::complete::take_until, character::complete::line_ending, multi::many1,
-    sequence::terminated, *,
-};
-use petgraph::{algo::dijkstra, graphmap::DiGraphMap, visit::EdgeRef};
-
-const MIN_MOVES: usize = 4;
-const MAX_MOVES_IN_SAME_DIRECTION: usize = 10;
-
-fn read_input(input: Option<&str>) -> String {
-    let input = match input {
-        None => include_str!("../../input.txt"),
-        Some(x) => x,
-    };
-
-    input.to_string()
+/// How many moves a crucible must and can make in the same direction.
+#[derive(Debug, Clone, Copy)]
+pub struct Moves {
+    pub min: usize,
+    pub max: usize,
 }
 
 fn parse(input: &str) -> IResult<&str, Data> {
-    let (input, lines) = many1(parse_line)(input)?;
-
-    let data = lines
-        .iter()
-        .enumerate()
-        .flat_map(|y| {
-            y.1.iter()
-                .enumerate()
-                .map(|x| (Coord::from((x.0 as isize, y.0 as isize)), *x.1))
-                .collect::<Vec<(Coord, char)>>()
-        })
-        .collect::<HashMap<Coord, char>>();
-
-    let length_x = lines[0].len();
-    let length_y = lines.len();
-
-    let data = Data {
-        length_x,
-        length_y,
-        grid: data,
-    };
-
-    Ok((input, data))
-}
-
-fn parse_line(input: &str) -> IResult<&str, Vec<char>> {
-    let (input, pipe) = terminated(take_until("\n"), line_ending)(input)?;
-    let pipe = pipe.to_string().chars().collect();
-    Ok((input, pipe))
+    let (input, grid) = context("a heat loss from 0 to 9", char_grid_of("0123456789"))(input)?;
+    Ok((input, Data { grid }))
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Data {
-    length_x: usize,
-    length_y: usize,
-    grid: HashMap<Coord, char>,
+pub struct Data {
+    pub grid: Grid<char>,
 }
 
 impl Data {
     // Get all neighbours clockwise
     fn get_neighbours(&self, coord: Coord) -> Vec<Option<(Coord, char)>> {
-        let neighbours_coords: Vec<Coord> =
-            Vec::from([(1, 0).into(), (0, 1).into(), (-1, 0).into(), (0, -1).into()]);
-
-        let neighbours = neighbours_coords
-            .iter()
-            .map(|nc| self.grid.get(&(coord + *nc)).map(|v| (coord + *nc, *v)))
-            .collect::<Vec<Option<(Coord, char)>>>();
-        neighbours
-    }
-}
-
-#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Ord, PartialOrd)]
-enum Direction {
-    Right = 0,
-    Down = 1,
-    Left = 2,
-    Up = 3,
-}
-
-#[derive(Debug, Eq, PartialEq, Clone, Copy, Ord, PartialOrd, Hash)]
-struct Coord {
-    x: isize,
-    y: isize,
-}
-
-impl From<(isize, isize)> for Coord {
-    fn from((x, y): (isize, isize)) -> Self {
-        Self { x, y }
-    }
-}
-
-impl Add<Coord> for Coord {
-    type Output = Coord;
-    fn add(self, rhs: Coord) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
+        get_neighbours(&self.grid, coord)
     }
 }
 
@@ -114,11 +48,11 @@ struct Crucible {
 }
 
 impl Crucible {
-    fn get_next_positions(&self, data: &Data) -> Vec<Crucible> {
+    fn get_next_positions(&self, data: &Data, moves: Moves) -> Vec<Crucible> {
         let neighbors = data.get_neighbours(self.position);
         let positions = match self.direction {
             Direction::Right => {
-                if self.nb_moves < MIN_MOVES {
+                if self.nb_moves < moves.min {
                     vec![
                         if let Some((npos, cost)) = neighbors[Direction::Right as usize] {
                             Some(Crucible {
@@ -167,7 +101,7 @@ impl Crucible {
                 }
             }
             Direction::Down => {
-                if self.nb_moves < MIN_MOVES {
+                if self.nb_moves < moves.min {
                     vec![
                         if let Some((npos, cost)) = neighbors[Direction::Down as usize] {
                             Some(Crucible {
@@ -216,7 +150,7 @@ impl Crucible {
                 }
             }
             Direction::Left => {
-                if self.nb_moves < MIN_MOVES {
+                if self.nb_moves < moves.min {
                     vec![
                         if let Some((npos, cost)) = neighbors[Direction::Left as usize] {
                             Some(Crucible {
@@ -265,7 +199,7 @@ impl Crucible {
                 }
             }
             Direction::Up => {
-                if self.nb_moves < MIN_MOVES {
+                if self.nb_moves < moves.min {
                     vec![
                         if let Some((npos, cost)) = neighbors[Direction::Up as usize] {
                             Some(Crucible {
@@ -315,106 +249,105 @@ impl Crucible {
             }
         };
         let mut positions: Vec<Crucible> = positions.iter().filter_map(|c| *c).collect();
-        // Remove positions that exceed 10 moves in the same direction
-        positions.retain(|c| c.nb_moves <= MAX_MOVES_IN_SAME_DIRECTION);
+        // Remove positions that exceed the moves allowed in the same direction
+        positions.retain(|c| c.nb_moves <= moves.max);
         positions
     }
 }
 
-fn run(input: String) -> usize {
-    let (_, data) = parse(&input).unwrap();
-    // dbg!(&data);
+/// Least heat loss from the top left to the bottom right block, `None` when
+/// the crucible cannot stop there, on a map too small for its moves.
+pub fn min_heat_loss(data: &Data, moves: Moves) -> Option<usize> {
+    let start_cost = data.grid[Coord::from((0, 0))].to_digit(10).unwrap() as usize;
+    let start = [Direction::Right, Direction::Down].map(|direction| Crucible {
+        direction,
+        position: Coord::from((0, 0)),
+        nb_moves: 0,
+        cost: start_cost,
+    });
+    let end = Coord::from((
+        data.grid.width() as isize - 1,
+        data.grid.height() as isize - 1,
+    ));
+
+    let search = dijkstra(
+        start,
+        |crucible| {
+            crucible
+                .get_next_positions(data, moves)
+                .into_iter()
+                .map(|c| (c, c.cost))
+        },
+        // A crucible starting on the bottom right block is there already
+        |crucible| {
+            crucible.position == end && (crucible.nb_moves >= moves.min || crucible.nb_moves == 0)
+        },
+    );
+    log::debug!("search stats = {:?}", search.stats);
+    stats::record("explored_states", search.stats.explored);
+
+    search.goal_distance()
+}
 
-    let mut to_process: VecDeque<Crucible> = VecDeque::new();
-    let mut g: DiGraphMap<Crucible, usize> = DiGraphMap::new();
+pub struct Day17;
 
-    let start = [
-        Crucible {
-            direction: Direction::Right,
-            position: Coord::from((0, 0)),
-            nb_moves: 0,
-            cost: data.grid[&Coord::from((0, 0)) as &Coord]
-                .to_digit(10)
-                .unwrap() as usize,
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "min_moves",
+            default: "1",
+            doc: "Moves a crucible makes in the same direction before turning, in part 1",
+            check: params::at_least::<0>,
         },
-        Crucible {
-            direction: Direction::Down,
-            position: Coord::from((0, 0)),
-            nb_moves: 0,
-            cost: data.grid[&Coord::from((0, 0)) as &Coord]
-                .to_digit(10)
-                .unwrap() as usize,
+        Param {
+            name: "max_moves",
+            default: "3",
+            doc: "Moves a crucible makes at most in the same direction, in part 1",
+            check: params::at_least::<1>,
+        },
+        Param {
+            name: "ultra_min_moves",
+            default: "4",
+            doc: "The same for the ultra crucibles of part 2",
+            check: params::at_least::<0>,
+        },
+        Param {
+            name: "ultra_max_moves",
+            default: "10",
+            doc: "The same for the ultra crucibles of part 2",
+            check: params::at_least::<1>,
         },
     ];
 
-    start.iter().for_each(|c| {
-        to_process.push_back(*c);
-    });
-
-    let mut seen = HashSet::new();
-    while !to_process.is_empty() {
-        let crucible = to_process.pop_front().unwrap();
-        let new_positions = crucible.get_next_positions(&data);
-        if !seen.contains(&crucible) {
-            seen.insert(crucible);
-            for c in new_positions.iter() {
-                g.add_edge(crucible, *c, c.cost);
-                if c.position
-                    != Coord::from((data.length_x as isize - 1, data.length_y as isize - 1))
-                {
-                    to_process.push_back(*c);
-                }
-            }
-        }
+    fn check_params(params: &Values) -> Result<(), String> {
+        params.check_order("min_moves", "max_moves")?;
+        params.check_order("ultra_min_moves", "ultra_max_moves")
     }
 
-    // println!("{:?}", Dot::new(&g));
-
-    let path0 = dijkstra(&g, start[0], None, |e| *e.weight());
-    let path1 = dijkstra(&g, start[1], None, |e| *e.weight());
-
-    let path: Vec<(&Crucible, &usize)> = path0
-        .iter()
-        .chain(path1.iter())
-        .filter(|p| {
-            p.0.position == Coord::from((data.length_x as isize - 1, data.length_y as isize - 1))
-        })
-        .collect();
-
-    dbg!(path
-        .iter()
-        .filter(|p| p.0.nb_moves >= MIN_MOVES)
-        .map(|p| *p.1)
-        .min()
-        .unwrap())
-}
-
-#[allow(dead_code)]
-fn print_text_map(coordinates: &[(usize, usize, char)], width: usize, height: usize) {
-    let mut grid = vec![vec!['.'; width]; height];
+    type Model = Data;
+    type Answer1 = Maybe<usize>;
+    type Answer2 = Maybe<usize>;
 
-    // Place the points on the grid
-    for &(x, y, v) in coordinates {
-        if x < width && y < height {
-            grid[y][x] = v; // Assuming the origin (0,0) is at the top-left corner
-        }
+    fn parse(input: &str) -> Result<Data, ParseError> {
+        parse_input(input, parse)
     }
 
-    // Print the grid row by row
-    for row in grid {
-        for cell in row {
-            print!("{}", cell);
-        }
-        println!(); // Newline at the end of each row
+    fn part1(data: &Data) -> Maybe<usize> {
+        let moves = Moves {
+            min: params::get::<Self, usize>("min_moves").expect("checked when loaded"),
+            max: params::get::<Self, usize>("max_moves").expect("checked when loaded"),
+        };
+        Maybe(min_heat_loss(data, moves))
     }
-}
-
-fn main() {
-    let input = read_input(None);
-
-    let answer = run(input);
 
-    println!("Answer: {}", answer);
+    fn part2(data: &Data) -> Maybe<usize> {
+        let moves = Moves {
+            min: params::get::<Self, usize>("ultra_min_moves").expect("checked when loaded"),
+            max: params::get::<Self, usize>("ultra_max_moves").expect("checked when loaded"),
+        };
+        Maybe(min_heat_loss(data, moves))
+    }
 }
 
 #[allow(unused_imports)]
@@ -431,41 +364,28 @@ mod tests {
     }
 
     #[test]
-    fn test_run1() {
-        let input = read_input(Some(indoc!(
-            "
-            2413432311323
-            3215453535623
-            3255245654254
-            3446585845452
-            4546657867536
-            1438598798454
-            4457876987766
-            3637877979653
-            4654967986887
-            4564679986453
-            1224686865563
-            2546548887735
-            4322674655533
-            "
-        )));
-        dbg!(&input);
-        let answer = run(input);
-        assert_eq!(answer, 94);
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day17>();
+    }
+
+    #[test]
+    fn test_min_heat_loss() {
+        let moves = Moves { min: 1, max: 3 };
+        let ultra = Moves { min: 4, max: 10 };
+        let data = Day17::parse("7\n").unwrap();
+        assert_eq!(min_heat_loss(&data, moves), Some(0));
+        assert_eq!(min_heat_loss(&data, ultra), Some(0));
+        let data = Day17::parse("12\n34\n").unwrap();
+        assert_eq!(min_heat_loss(&data, moves), Some(6));
+        assert_eq!(min_heat_loss(&data, ultra), None);
+        assert_eq!(Day17::part2(&data).to_string(), "none");
     }
+
     #[test]
-    fn test_run2() {
-        let input = read_input(Some(indoc!(
-            "
-            111111111111
-            999999999991
-            999999999991
-            999999999991
-            999999999991
-            "
-        )));
-        dbg!(&input);
-        let answer = run(input);
-        assert_eq!(answer, 71);
+    fn test_parse_error() {
+        assert_eq!(
+            Day17::parse("12a\n123\n").unwrap_err(),
+            ParseError::new(1, 3, "12a", "a heat loss from 0 to 9")
+        );
     }
 }
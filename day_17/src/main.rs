@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_17::Day17;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day17>()
+}
@@ -0,0 +1,183 @@
+//! Day 18: Lavaduct Lagoon
+//!
+//! The model is the dig plan, [`lagoon_volume`] measures what a plan digs.
+
+use aoc_common::{
+    checked,
+    geometry::{boundary_points, interior_points},
+    parse::{parse_input, IResult, ParseError},
+    Coord, Direction, Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{char, line_ending, multispace0, multispace1, one_of},
+    combinator::{eof, recognize, value},
+    error::context,
+    multi::many_till,
+    sequence::{pair, preceded},
+};
+
+fn parse(input: &str) -> IResult<&str, Data> {
+    // Stop on the first bad line instead of leaving it unparsed
+    let (input, (cubes, _)) = many_till(parse_line, preceded(multispace0, eof))(input)?;
+
+    let data = Data { cubes };
+
+    Ok((input, data))
+}
+
+fn parse_line(input: &str) -> IResult<&str, Cube> {
+    let (input, direction) = context(
+        "a direction (R, D, L or U)",
+        alt((
+            value(Direction::Right, char('R')),
+            value(Direction::Down, char('D')),
+            value(Direction::Left, char('L')),
+            value(Direction::Up, char('U')),
+        )),
+    )(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, distance) = nom::character::complete::u32(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, _) = context("\"(#\"", tag("(#"))(input)?;
+    // The color is the real instruction: 5 digits of distance then the direction
+    let (input, color) = context(
+        "a color of 6 hex digits, the last one from 0 to 3",
+        recognize(pair(
+            take_while_m_n(5, 5, |c: char| c.is_ascii_hexdigit()),
+            one_of("0123"),
+        )),
+    )(input)?;
+    let (input, _) = tag(")")(input)?;
+    let (input, _) = line_ending(input)?;
+
+    Ok((
+        input,
+        Cube {
+            direction,
+            distance: distance as usize,
+            color: color.to_string(),
+        },
+    ))
+}
+
+#[derive(Debug)]
+pub struct Data {
+    pub cubes: Vec<Cube>,
+}
+
+/// One line of the dig plan. The color holds the real instruction of part 2.
+#[derive(Debug)]
+pub struct Cube {
+    pub direction: Direction,
+    pub distance: usize,
+    pub color: String,
+}
+
+fn direction_from_digit(x: usize) -> Direction {
+    match x {
+        0 => Direction::Right,
+        1 => Direction::Down,
+        2 => Direction::Left,
+        3 => Direction::Up,
+        _ => unreachable!("the parser only accepts directions from 0 to 3"),
+    }
+}
+
+/// The corners of the trench, in digging order.
+pub fn dig(cubes: &[Cube]) -> Vec<Coord> {
+    cubes
+        .iter()
+        .scan(Coord::new(0, 0), |current_coord, cube| {
+            let offset = cube.direction.offset();
+            let distance: isize = checked::cast(cube.distance);
+            *current_coord = Coord::new(
+                checked::add(current_coord.x, checked::mul(offset.x, distance)),
+                checked::add(current_coord.y, checked::mul(offset.y, distance)),
+            );
+            Some(*current_coord)
+        })
+        .collect()
+}
+
+/// Cubic meters of lava held by the trench and the interior dug out with it.
+pub fn lagoon_volume(cubes: &[Cube]) -> usize {
+    let corners = dig(cubes);
+    // Each cube is a lattice point: the ones on the trench plus, from Pick's
+    // theorem, the ones inside
+    checked::cast(boundary_points(&corners) + interior_points(&corners))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Model = Data;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Data, ParseError> {
+        parse_input(input, parse)
+    }
+
+    fn part1(data: &Data) -> usize {
+        log::debug!("data = {:?}", data);
+        lagoon_volume(&data.cubes)
+    }
+
+    fn part2(data: &Data) -> usize {
+        // Fix the data
+        let cubes = data
+            .cubes
+            .iter()
+            .map(|cube| {
+                let distance = usize::from_str_radix(&cube.color[..5], 16).unwrap();
+                let direction = direction_from_digit(cube.color[5..].parse::<usize>().unwrap());
+
+                Cube {
+                    direction,
+                    distance,
+                    color: cube.color.clone(),
+                }
+            })
+            .collect::<Vec<Cube>>();
+        log::debug!("cubes = {:?}", cubes);
+
+        lagoon_volume(&cubes)
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day18>();
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = indoc!(
+            "
+            R 6 (#70c710)
+            X 5 (#0dc571)
+            "
+        );
+        assert_eq!(
+            Day18::parse(input).unwrap_err(),
+            ParseError::new(2, 1, "X 5 (#0dc571)", "a direction (R, D, L or U)")
+        );
+    }
+}
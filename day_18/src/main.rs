@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_18::Day18;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day18>()
+}
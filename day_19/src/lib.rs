@@ -0,0 +1,595 @@
+//! Day 19: Aplenty
+//!
+//! The model holds the workflows by name and the parts to sort through them.
+
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, digit1, line_ending, multispace0},
+    combinator::{consumed, cut, eof, map_res, value},
+    error::{context, VerboseError},
+    multi::{many_till, separated_list1},
+    sequence::{preceded, separated_pair},
+    Offset,
+};
+
+use aoc_common::{
+    params::{self, Param, Values},
+    parse::{failure, parse_input, IResult, ParseError},
+    Solution,
+};
+
+use nom::character::complete::char as nomchar;
+
+fn parse(input: &str) -> IResult<&str, Data> {
+    // Workflows up to the empty line, then parts up to the end
+    let (rest, (lines, _)) = many_till(consumed(parse_wf_line), line_ending)(input)?;
+    check_workflows(input, &lines)?;
+    let (rest, (parts, _)) = many_till(parse_p_line, preceded(multispace0, eof))(rest)?;
+
+    let workflow = lines
+        .into_iter()
+        .map(|(_, workflow)| workflow)
+        .collect::<HashMap<String, Vec<Rule>>>();
+    let data = Data { workflow, parts };
+    Ok((rest, data))
+}
+
+/// The rules of a workflow line, where they are in it.
+fn rule_texts(line: &str) -> Vec<&str> {
+    line[line.find('{').unwrap() + 1..line.rfind('}').unwrap()]
+        .split(',')
+        .collect()
+}
+
+fn destination(rule: &str) -> &str {
+    rule.rsplit(':').next().unwrap()
+}
+
+/// Every part has to go from `in` to A or R: through workflows that are
+/// defined once, that end with a rule without a condition and that never
+/// lead back to themselves.
+fn check_workflows<'a>(
+    input: &'a str,
+    lines: &[(&'a str, (String, Vec<Rule>))],
+) -> Result<(), nom::Err<VerboseError<&'a str>>> {
+    // The errors point at where the names are in the input
+    let at = |text: &'a str| &input[input.offset(text)..];
+    let mut workflows: HashMap<&str, Vec<&str>> = HashMap::new();
+    let names = lines
+        .iter()
+        .map(|(line, _)| &line[..line.find('{').unwrap()])
+        .collect::<Vec<_>>();
+    for ((line, (_, rules)), name) in lines.iter().zip(names.iter().copied()) {
+        let texts = rule_texts(line);
+        if rules.last().is_some_and(|rule| rule.rate.is_some()) {
+            let last = texts.last().unwrap();
+            return Err(failure(at(last), "a last rule without a condition"));
+        }
+        let destinations = texts.into_iter().map(destination).collect();
+        if workflows.insert(name, destinations).is_some() {
+            return Err(failure(at(line), "a workflow defined once"));
+        }
+    }
+    if !workflows.contains_key("in") {
+        return Err(failure(input, "an in workflow to start from"));
+    }
+    for destination in names.iter().flat_map(|name| &workflows[name]) {
+        if !["A", "R"].contains(destination) && !workflows.contains_key(destination) {
+            return Err(failure(
+                at(destination),
+                "a workflow that is defined, A or R",
+            ));
+        }
+    }
+
+    // Depth-first from each workflow, a destination still on the path is a
+    // loop
+    fn visit<'a>(
+        name: &'a str,
+        workflows: &HashMap<&'a str, Vec<&'a str>>,
+        path: &mut Vec<&'a str>,
+        done: &mut HashSet<&'a str>,
+    ) -> Option<&'a str> {
+        if done.contains(name) {
+            return None;
+        }
+        path.push(name);
+        for destination in workflows.get(name).into_iter().flatten() {
+            if path.contains(destination) {
+                return Some(destination);
+            }
+            if let Some(looping) = visit(destination, workflows, path, done) {
+                return Some(looping);
+            }
+        }
+        path.pop();
+        done.insert(name);
+        None
+    }
+    let mut done = HashSet::new();
+    for name in names.iter().copied() {
+        if let Some(looping) = visit(name, &workflows, &mut Vec::new(), &mut done) {
+            return Err(failure(
+                at(looping),
+                "a workflow that does not lead back to itself",
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn parse_p_line(input: &str) -> IResult<&str, Part> {
+    let (input, _) = context("\"{x=\"", tag("{x="))(input)?;
+    let (input, x) = parse_value(input)?;
+    let (input, _) = context("\",m=\"", tag(",m="))(input)?;
+    let (input, m) = parse_value(input)?;
+    let (input, _) = context("\",a=\"", tag(",a="))(input)?;
+    let (input, a) = parse_value(input)?;
+    let (input, _) = context("\",s=\"", tag(",s="))(input)?;
+    let (input, s) = parse_value(input)?;
+    let (input, _) = nomchar('}')(input)?;
+    let (input, _) = line_ending(input)?;
+    Ok((input, Part { x, m, a, s }))
+}
+
+fn parse_wf_line(input: &str) -> IResult<&str, (String, Vec<Rule>)> {
+    let (input, name) = context("a workflow name", alpha1)(input)?;
+    let (input, _) = nomchar('{')(input)?;
+    let (input, rules) = separated_list1(tag(","), parse_rules)(input)?;
+    let (input, _) = context("',' or '}' after a rule", nomchar('}'))(input)?;
+    let (input, _) = line_ending(input)?;
+
+    Ok((input, (name.to_string(), rules)))
+}
+
+fn parse_rules(input: &str) -> IResult<&str, Rule> {
+    let (input, rule) = alt((parse_c_rule, parse_d_rule))(input)?;
+    Ok((input, rule))
+}
+
+fn parse_value(input: &str) -> IResult<&str, usize> {
+    context("a rating value", map_res(digit1, str::parse))(input)
+}
+
+fn parse_c_rule(input: &str) -> IResult<&str, Rule> {
+    let (input, rate) = alt((nomchar('x'), nomchar('m'), nomchar('a'), nomchar('s')))(input)?;
+    let (input, op) = alt((value(Op::Inf, nomchar('<')), value(Op::Sup, nomchar('>'))))(input)?;
+    // From here on it can't be a workflow name anymore
+    let (input, (value, destination)) = cut(separated_pair(
+        parse_value,
+        context("':'", nomchar(':')),
+        context("a destination workflow", alpha1),
+    ))(input)?;
+    let rule = Rule {
+        rate: Some(rate),
+        op: Some(op),
+        value: Some(value),
+        dest: destination.to_string(),
+    };
+    Ok((input, rule))
+}
+
+fn parse_d_rule(input: &str) -> IResult<&str, Rule> {
+    let (input, destination) = alpha1(input)?;
+    let rule = Rule {
+        rate: None,
+        op: None,
+        value: None,
+        dest: destination.to_string(),
+    };
+    Ok((input, rule))
+}
+
+#[derive(Debug)]
+pub struct Data {
+    pub workflow: HashMap<String, Vec<Rule>>,
+    pub parts: Vec<Part>,
+}
+
+/// A rule of a workflow. The last rule of a workflow only has a destination.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub rate: Option<char>,
+    pub op: Option<Op>,
+    pub value: Option<usize>,
+    pub dest: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Sup,
+    Inf,
+}
+
+#[derive(Debug, Clone)]
+pub struct Part {
+    pub x: usize,
+    pub m: usize,
+    pub a: usize,
+    pub s: usize,
+}
+
+impl Part {
+    pub fn sum(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+#[derive(Debug, Clone)]
+/// All the parts with ratings in these ranges.
+struct PartRange {
+    x: Range<usize>,
+    m: Range<usize>,
+    a: Range<usize>,
+    s: Range<usize>,
+}
+
+impl PartRange {
+    fn combinations(&self) -> usize {
+        (self.x.len()) * (self.m.len()) * (self.a.len()) * (self.s.len())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct WFStep {
+    part: PartRange,
+    wf_name: String,
+    rule_index: usize,
+}
+
+impl WFStep {
+    /// Every part with its ratings in `ratings`, entering the `in` workflow.
+    fn new(ratings: Range<usize>) -> Self {
+        Self {
+            part: PartRange {
+                x: ratings.clone(),
+                m: ratings.clone(),
+                a: ratings.clone(),
+                s: ratings,
+            },
+            wf_name: String::from("in"),
+            rule_index: 0,
+        }
+    }
+}
+
+fn process_wf(workflow: &HashMap<String, Vec<Rule>>, wf_name: String, part: Part) -> Option<&str> {
+    let rules = workflow.get(&wf_name).unwrap();
+    for rule in rules.iter() {
+        match rule.rate {
+            Some('x') => match rule.op.unwrap() {
+                Op::Sup => {
+                    if part.x > rule.value.unwrap() {
+                        return Some(&rule.dest);
+                    }
+                }
+                Op::Inf => {
+                    if part.x < rule.value.unwrap() {
+                        return Some(&rule.dest);
+                    }
+                }
+            },
+            Some('m') => match rule.op.unwrap() {
+                Op::Sup => {
+                    if part.m > rule.value.unwrap() {
+                        return Some(&rule.dest);
+                    }
+                }
+                Op::Inf => {
+                    if part.m < rule.value.unwrap() {
+                        return Some(&rule.dest);
+                    }
+                }
+            },
+            Some('a') => match rule.op.unwrap() {
+                Op::Sup => {
+                    if part.a > rule.value.unwrap() {
+                        return Some(&rule.dest);
+                    }
+                }
+                Op::Inf => {
+                    if part.a < rule.value.unwrap() {
+                        return Some(&rule.dest);
+                    }
+                }
+            },
+            Some('s') => match rule.op.unwrap() {
+                Op::Sup => {
+                    if part.s > rule.value.unwrap() {
+                        return Some(&rule.dest);
+                    }
+                }
+                Op::Inf => {
+                    if part.s < rule.value.unwrap() {
+                        return Some(&rule.dest);
+                    }
+                }
+            },
+            Some(_) => panic!("Unknown rule rate: {}", rule.rate.unwrap()),
+            None => {
+                return Some(&rule.dest);
+            }
+        }
+    }
+    None
+}
+
+fn process_wf_steps(workflow: &HashMap<String, Vec<Rule>>, wf_step: WFStep) -> Vec<Option<WFStep>> {
+    let rules = workflow.get(&wf_step.wf_name).unwrap();
+    let rule = rules.get(wf_step.rule_index).unwrap();
+    match rule.rate {
+        Some(_) => process_rate_rule(&wf_step, rule),
+        None => process_redirect_rule(&wf_step, rule),
+    }
+}
+
+fn process_redirect_rule(wf_step: &WFStep, rule: &Rule) -> Vec<Option<WFStep>> {
+    let mut nwf_steps = Vec::new();
+    let mut nwf_step_ok = wf_step.clone();
+    nwf_step_ok.wf_name = rule.dest.clone();
+    nwf_step_ok.rule_index = 0;
+    nwf_steps.push(Some(nwf_step_ok));
+    nwf_steps
+}
+
+fn process_rate_rule(wf_step: &WFStep, rule: &Rule) -> Vec<Option<WFStep>> {
+    let mut nwf_steps = Vec::new();
+    let mut nwf_step_ok = wf_step.clone();
+    let mut nwf_step_ko = wf_step.clone();
+
+    // We return 2 x WFStep, one if the rule is ok and one if the rule is ko
+    // get_range return a mutable reference to the impacted range
+    let part_rate_ok = get_range(rule, &mut nwf_step_ok);
+    let part_rate_ko = get_range(rule, &mut nwf_step_ko);
+
+    // Initial range is 1..4001  note this is half open range 1 <= x < 4001
+    // If we split the range in 2 with x<2000
+    // we get 1..2000 and 2000..4001
+    // If x > 2000:
+    // we get 2001..4001 and 1..2001
+    if rule.op.unwrap() == Op::Inf {
+        let range = part_rate_ok.start..rule.value.unwrap();
+        *part_rate_ok = range;
+        let range = rule.value.unwrap()..part_rate_ko.end;
+        *part_rate_ko = range;
+    } else {
+        let range = rule.value.unwrap() + 1..part_rate_ok.end;
+        *part_rate_ok = range;
+        let range = part_rate_ko.start..rule.value.unwrap() + 1;
+        *part_rate_ko = range;
+    }
+
+    if Range::is_empty(part_rate_ok) {
+        nwf_steps.push(None);
+    } else {
+        nwf_step_ok.wf_name = rule.dest.clone();
+        nwf_step_ok.rule_index = 0;
+        nwf_steps.push(Some(nwf_step_ok));
+    }
+
+    if Range::is_empty(part_rate_ko) {
+        nwf_steps.push(None);
+    } else {
+        nwf_step_ko.rule_index += 1;
+        nwf_steps.push(Some(nwf_step_ko));
+    }
+    nwf_steps
+}
+
+fn get_range<'a>(rule: &'a Rule, nwf_step: &'a mut WFStep) -> &'a mut Range<usize> {
+    match rule.rate.unwrap() {
+        'x' => &mut nwf_step.part.x,
+        'm' => &mut nwf_step.part.m,
+        'a' => &mut nwf_step.part.a,
+        's' => &mut nwf_step.part.s,
+        _ => panic!("Unknown rule rate: {}", rule.rate.unwrap()),
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "min_rating",
+            default: "1",
+            doc: "Lowest rating of a part in part 2",
+            check: params::at_least::<0>,
+        },
+        Param {
+            name: "max_rating",
+            default: "4000",
+            doc: "Highest rating of a part in part 2",
+            check: params::at_least::<0>,
+        },
+    ];
+
+    fn check_params(params: &Values) -> Result<(), String> {
+        params.check_order("min_rating", "max_rating")
+    }
+
+    type Model = Data;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Data, ParseError> {
+        parse_input(input, parse)
+    }
+
+    fn part1(data: &Data) -> usize {
+        log::debug!("data = {:?}", data);
+
+        let mut accepted = Vec::new();
+        for part in data.parts.iter() {
+            let mut wf_name = String::from("in");
+            // Use a loop and not a recursive function to process the workflow, to avoid a potential stack overflow
+            loop {
+                // dbg!(&wf_name);
+                match process_wf(&data.workflow, wf_name.clone(), part.clone()).unwrap() {
+                    "A" => {
+                        accepted.push(part);
+                        break;
+                    }
+                    "R" => {
+                        break;
+                    }
+                    name => wf_name = name.to_string(),
+                }
+            }
+        }
+        log::debug!("accepted = {:?}", accepted);
+        accepted.iter().map(|x| x.sum()).sum::<usize>()
+    }
+
+    fn part2(data: &Data) -> usize {
+        log::debug!("data = {:?}", data);
+
+        let mut accepted = Vec::new();
+        let mut wf_steps = Vec::new();
+        let min = params::get::<Self, usize>("min_rating").expect("checked when loaded");
+        let max = params::get::<Self, usize>("max_rating").expect("checked when loaded");
+        wf_steps.push(WFStep::new(min..max + 1));
+
+        while let Some(wf_step) = wf_steps.pop() {
+            let nwf_steps = process_wf_steps(&data.workflow, wf_step);
+
+            for nwf_step in nwf_steps {
+                match nwf_step {
+                    Some(nwf_step) => match nwf_step.wf_name.as_str() {
+                        "A" => {
+                            accepted.push(nwf_step.part);
+                        }
+                        "R" => {}
+                        _ => {
+                            wf_steps.push(nwf_step);
+                        }
+                    },
+                    None => {
+                        log::trace!(
+                            "\"We should not pass here unless a range in empty (start>end)\" = {:?}",
+                            "We should not pass here unless a range in empty (start>end)"
+                        );
+                    }
+                }
+            }
+        }
+        log::debug!("accepted = {:?}", accepted);
+        accepted.iter().map(|x| x.combinations()).sum::<usize>()
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day19>();
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = indoc!(
+            "
+            in{a<2006:qkq,m=2090:A,R}
+
+            {x=787,m=2655,a=1222,s=2876}
+            "
+        );
+        assert_eq!(
+            Day19::parse(input).unwrap_err(),
+            ParseError::new(
+                1,
+                16,
+                "in{a<2006:qkq,m=2090:A,R}",
+                "',' or '}' after a rule"
+            )
+        );
+    }
+
+    #[test]
+    fn test_workflow_errors() {
+        let error = |workflows: &str| {
+            Day19::parse(&format!("{}\n{{x=787,m=2655,a=1222,s=2876}}\n", workflows)).unwrap_err()
+        };
+        assert_eq!(
+            Day19::parse("\n\n").unwrap_err(),
+            ParseError::new(1, 1, "", "an in workflow to start from")
+        );
+        assert_eq!(
+            error("px{a<2006:A,R}\n"),
+            ParseError::new(1, 1, "px{a<2006:A,R}", "an in workflow to start from")
+        );
+        assert_eq!(
+            error("in{a<2006:qkq,R}\n"),
+            ParseError::new(
+                1,
+                11,
+                "in{a<2006:qkq,R}",
+                "a workflow that is defined, A or R"
+            )
+        );
+        assert_eq!(
+            error("in{a<2006:A,x>5:R}\n"),
+            ParseError::new(
+                1,
+                13,
+                "in{a<2006:A,x>5:R}",
+                "a last rule without a condition"
+            )
+        );
+        assert_eq!(
+            error("in{a<2006:A,R}\nin{R}\n"),
+            ParseError::new(2, 1, "in{R}", "a workflow defined once")
+        );
+        assert_eq!(
+            error("in{a<2006:px,R}\npx{s>5:A,qs}\nqs{px}\n"),
+            ParseError::new(
+                3,
+                4,
+                "qs{px}",
+                "a workflow that does not lead back to itself"
+            )
+        );
+    }
+
+    #[test]
+    fn test_combinations() {
+        let part = PartRange {
+            x: 1..4001,
+            m: 1..4001,
+            a: 1..4001,
+            s: 1..4001,
+        };
+        let combinations = part.combinations();
+        assert_eq!(combinations, 4000 * 4000 * 4000 * 4000);
+    }
+
+    #[test]
+    fn test_combinations2() {
+        let part = PartRange {
+            x: 1..2001,
+            m: 2001..4001,
+            a: 1..4001,
+            s: 1..4001,
+        };
+        let combinations = part.combinations();
+        assert_eq!(combinations, 2000 * 2000 * 4000 * 4000);
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_19::Day19;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day19>()
+}
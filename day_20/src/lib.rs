@@ -0,0 +1,859 @@
+//! Day 20: Pulse Propagation
+//!
+//! The model is the modules of the circuit by name. The [`Component`]s keep
+//! their state, so clone them to push the button from scratch again.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha0, alpha1, line_ending, multispace0, space1},
+    combinator::{consumed, eof, value},
+    error::context,
+    multi::{many_till, separated_list1},
+    sequence::preceded,
+    Offset,
+};
+
+use aoc_common::{
+    checked,
+    math::lcm_all,
+    params::{self, Param, Values},
+    parse::{failure, parse_input, IResult, ParseError},
+    render::Rgb,
+    simulation::{Simulate, Simulation},
+    solution::Maybe,
+    stats, Coord, Grid, Solution,
+};
+
+fn parse(start: &str) -> IResult<&str, HashMap<String, Component>> {
+    // Stop on the first bad line instead of leaving it unparsed
+    let (input, (lines, _)) = many_till(consumed(parse_line), preceded(multispace0, eof))(start)?;
+
+    let mut components = HashMap::new();
+    for (line, (name, component)) in lines {
+        if components.insert(name, component).is_some() {
+            return Err(failure(
+                &start[start.offset(line)..],
+                "a module defined once",
+            ));
+        }
+    }
+    // The button pushes the broadcaster
+    if !components.contains_key("broadcaster") {
+        return Err(failure(start, "a broadcaster"));
+    }
+
+    Ok((input, components))
+}
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+}
+
+fn parse_line(input: &str) -> IResult<&str, (String, Component)> {
+    let (input, component) = context(
+        "a component (broadcaster, %name or &name)",
+        alt((
+            value(Kind::Broadcaster, tag("broadcaster")),
+            value(Kind::FlipFlop, tag("%")),
+            value(Kind::Conjunction, tag("&")),
+        )),
+    )(input)?;
+    let (input, mut name) = alpha0(input)?;
+    let (input, _) = space1(input)?;
+    let (input, _) = tag("->")(input)?;
+    let (input, dests) = alt((
+        separated_list1(tag(","), parse_multi_dest),
+        parse_unique_dest,
+    ))(input)?;
+    let (input, _) = line_ending(input)?;
+
+    let dests = dests
+        .into_iter()
+        .map(|x| Connection::new(name.to_string(), x.trim().to_string()))
+        .collect::<Vec<Connection>>();
+
+    let component = match component {
+        Kind::Broadcaster => {
+            name = "broadcaster";
+            let dests = dests
+                .into_iter()
+                .map(|Connection { from: _, to }| Connection::new(name.to_string(), to))
+                .collect();
+            Component::Broadcaster(Broadcaster::new(name.to_string(), dests))
+        }
+        Kind::FlipFlop => Component::FlipFlop(FlipFlop::new(name.to_string(), dests)),
+        Kind::Conjunction => {
+            // conjonctions.push(name.to_string());
+            Component::Conjunction(Conjunction::new(name.to_string(), dests))
+        }
+    };
+
+    Ok((input, (name.to_string(), component)))
+}
+
+fn parse_unique_dest(input: &str) -> IResult<&str, Vec<&str>> {
+    let (input, dest) = alpha1(input)?;
+
+    Ok((input, vec![dest]))
+}
+
+fn parse_multi_dest(input: &str) -> IResult<&str, &str> {
+    let (input, _) = space1(input)?;
+    let (input, dest) = alpha1(input)?;
+
+    Ok((input, dest))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pulse {
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    On,
+    Off,
+}
+
+#[derive(Debug, Clone)]
+struct PulseCounter {
+    low: usize,
+    high: usize,
+}
+
+impl PulseCounter {
+    fn new() -> Self {
+        Self { low: 0, high: 0 }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Connection {
+    from: String,
+    to: String,
+}
+
+impl Connection {
+    fn new(from: String, to: String) -> Self {
+        Self { from, to }
+    }
+}
+
+impl From<(&str, &str)> for Connection {
+    fn from((from, to): (&str, &str)) -> Self {
+        Self {
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Component {
+    Broadcaster(Broadcaster),
+    FlipFlop(FlipFlop),
+    Conjunction(Conjunction),
+}
+
+impl Component {
+    fn output_connections(&self) -> &[Connection] {
+        match self {
+            Component::Broadcaster(broadcaster) => &broadcaster.output_connection,
+            Component::FlipFlop(flip_flop) => &flip_flop.output_connection,
+            Component::Conjunction(conjonction) => &conjonction.output_connection,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FlipFlop {
+    name: String,
+    state: State,
+    output_connection: Vec<Connection>,
+}
+
+impl FlipFlop {
+    fn new(name: String, connections: Vec<Connection>) -> Self {
+        Self {
+            name,
+            state: State::Off,
+            output_connection: connections,
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn high_pulse(
+        &self,
+        pulse_counter: &mut PulseCounter,
+        outputs: &mut HashMap<String, Vec<(Connection, Pulse)>>,
+    ) {
+        // Nothing should happen here !
+        // The outputs should not be touched in this case and especially not cleared!
+    }
+
+    fn low_pulse(
+        &mut self,
+        pulse_counter: &mut PulseCounter,
+        outputs: &mut HashMap<String, Vec<(Connection, Pulse)>>,
+    ) {
+        if self.state == State::Off {
+            self.state = State::On;
+            let mut out = Vec::new();
+            for conn in self.output_connection.iter() {
+                pulse_counter.high += 1;
+                log::trace!("{}: sends a high pulse to {}", self.name, conn.to);
+                out.push((conn.clone(), Pulse::High));
+            }
+            outputs.insert(self.name.clone(), out);
+        } else {
+            self.state = State::Off;
+            let mut out = Vec::new();
+            for conn in self.output_connection.iter() {
+                pulse_counter.low += 1;
+                log::trace!("{}: sends a low pulse to {}", self.name, conn.to);
+                out.push((conn.clone(), Pulse::Low));
+            }
+            outputs.insert(self.name.clone(), out);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Conjunction {
+    name: String,
+    output_connection: Vec<Connection>,
+    input_connections: Vec<Connection>,
+    inputs: Vec<(Connection, Pulse)>,
+}
+
+impl Conjunction {
+    fn new(name: String, connections: Vec<Connection>) -> Self {
+        Self {
+            name,
+            output_connection: connections,
+            input_connections: Vec::new(),
+            inputs: Vec::new(),
+        }
+    }
+    fn high_pulse(
+        &mut self,
+        pulse_counter: &mut PulseCounter,
+        outputs: &mut HashMap<String, Vec<(Connection, Pulse)>>,
+    ) {
+        self.get_outputs(outputs, pulse_counter);
+    }
+
+    fn low_pulse(
+        &mut self,
+        pulse_counter: &mut PulseCounter,
+        outputs: &mut HashMap<String, Vec<(Connection, Pulse)>>,
+    ) {
+        self.get_outputs(outputs, pulse_counter);
+    }
+
+    fn get_outputs(
+        &mut self,
+        outputs: &mut HashMap<String, Vec<(Connection, Pulse)>>,
+        pulse_counter: &mut PulseCounter,
+    ) {
+        self.get_inputs(outputs);
+        outputs.get_mut(&self.name).unwrap().clear();
+        if self
+            .inputs
+            .iter()
+            .map(|(_, p)| p)
+            .all(|p| *p == Pulse::High)
+        {
+            for conn in self.output_connection.iter() {
+                log::trace!("{}: sends a low pulse to {}", self.name, conn.to);
+                pulse_counter.low += 1;
+                outputs
+                    .get_mut(&self.name)
+                    .unwrap()
+                    .push((conn.clone(), Pulse::Low));
+            }
+        } else {
+            for conn in self.output_connection.iter() {
+                log::trace!("{}: sends a high pulse to {}", self.name, conn.to);
+                pulse_counter.high += 1;
+                outputs
+                    .get_mut(&self.name)
+                    .unwrap()
+                    .push((conn.clone(), Pulse::High));
+            }
+        }
+    }
+
+    fn get_inputs(&mut self, outputs: &HashMap<String, Vec<(Connection, Pulse)>>) {
+        self.inputs.clear();
+        for input in self.input_connections.iter() {
+            if let Some(output) = outputs
+                .get(&input.from)
+                .unwrap()
+                .iter()
+                .find(|(conn, _p)| *conn == *input)
+            {
+                self.inputs.push(output.clone());
+            } else {
+                self.inputs.push((input.clone(), Pulse::Low));
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Broadcaster {
+    name: String,
+    output_connection: Vec<Connection>,
+}
+impl Broadcaster {
+    fn new(name: String, connections: Vec<Connection>) -> Self {
+        Self {
+            name,
+            output_connection: connections,
+        }
+    }
+    fn high_pulse(
+        &mut self,
+        pulse_counter: &mut PulseCounter,
+        outputs: &mut HashMap<String, Vec<(Connection, Pulse)>>,
+    ) {
+        outputs.get_mut(&self.name).unwrap().clear();
+        for conn in self.output_connection.iter_mut() {
+            log::trace!("{}: sends a low pulse to {}", self.name, conn.to);
+            pulse_counter.high += 1;
+            outputs
+                .get_mut(&self.name)
+                .unwrap()
+                .push((conn.clone(), Pulse::High));
+        }
+    }
+
+    fn low_pulse(
+        &mut self,
+        pulse_counter: &mut PulseCounter,
+        outputs: &mut HashMap<String, Vec<(Connection, Pulse)>>,
+    ) {
+        outputs.get_mut(&self.name).unwrap().clear();
+        for conn in self.output_connection.iter() {
+            log::trace!("{}: sends a low pulse to {}", self.name, conn.to);
+            pulse_counter.low += 1;
+            outputs
+                .get_mut(&self.name)
+                .unwrap()
+                .push((conn.clone(), Pulse::Low));
+        }
+    }
+}
+
+/// The circuit once wired, ready to push the button.
+#[derive(Debug, Clone)]
+struct Circuit {
+    components: HashMap<String, Component>,
+    outputs: HashMap<String, Vec<(Connection, Pulse)>>,
+    pulse_counter: PulseCounter,
+}
+
+impl Circuit {
+    fn new(components: &HashMap<String, Component>) -> Self {
+        let mut components = components.clone();
+        let connections = components
+            .values()
+            .flat_map(|component| component.output_connections().to_vec())
+            .collect::<Vec<_>>();
+
+        let mut outputs: HashMap<String, Vec<(Connection, Pulse)>> = HashMap::new();
+        // Initialise all outputs
+        for name in components.keys() {
+            outputs.insert(name.clone(), Vec::new());
+        }
+
+        // Populate input connections for conjonction components
+        for comp in components.iter_mut() {
+            if let Component::Conjunction(conjonction) = comp.1 {
+                for Connection { from, to } in connections.iter() {
+                    if to == &conjonction.name {
+                        conjonction
+                            .input_connections
+                            .push(Connection::new(from.clone(), to.clone()));
+                    }
+                }
+            }
+        }
+
+        log::debug!("components = {:?}", components);
+
+        Self {
+            components,
+            outputs,
+            pulse_counter: PulseCounter::new(),
+        }
+    }
+
+    /// Push the button once. `watch` sees every pulse before it is delivered
+    /// and stops everything by returning true, then so does this function.
+    fn push_button(&mut self, mut watch: impl FnMut(&Connection, Pulse) -> bool) -> bool {
+        let mut stack: VecDeque<(Connection, Pulse)> = VecDeque::new();
+
+        stack.push_back((Connection::from(("button", "broadcaster")), Pulse::Low));
+        log::trace!("button: sends a low pulse to broadcaster");
+        self.pulse_counter.low += 1;
+        while let Some((conn, pulse)) = stack.pop_front() {
+            if watch(&conn, pulse) {
+                return true;
+            }
+            if let Some(component) = self.components.get_mut(&conn.to) {
+                match component {
+                    Component::Broadcaster(comp) => match pulse {
+                        Pulse::Low => {
+                            comp.low_pulse(&mut self.pulse_counter, &mut self.outputs);
+                            for output in self.outputs.get(&comp.name).unwrap() {
+                                stack.push_back(output.clone());
+                            }
+                        }
+                        Pulse::High => {
+                            comp.high_pulse(&mut self.pulse_counter, &mut self.outputs);
+                            for output in self.outputs.get(&comp.name).unwrap() {
+                                stack.push_back(output.clone());
+                            }
+                        }
+                    },
+                    Component::FlipFlop(comp) => match pulse {
+                        Pulse::Low => {
+                            comp.low_pulse(&mut self.pulse_counter, &mut self.outputs);
+                            for output in self.outputs.get(&comp.name).unwrap() {
+                                stack.push_back(output.clone());
+                            }
+                        }
+                        Pulse::High => {
+                            comp.high_pulse(&mut self.pulse_counter, &mut self.outputs);
+                            // In this case nothing happens.
+                            // The component produce no new outputs so nothing should be pushed on the stack
+                        }
+                    },
+                    Component::Conjunction(comp) => match pulse {
+                        Pulse::Low => {
+                            comp.low_pulse(&mut self.pulse_counter, &mut self.outputs);
+                            for output in self.outputs.get(&comp.name).unwrap() {
+                                stack.push_back(output.clone());
+                            }
+                        }
+                        Pulse::High => {
+                            comp.high_pulse(&mut self.pulse_counter, &mut self.outputs);
+                            for output in self.outputs.get(&comp.name).unwrap() {
+                                stack.push_back(output.clone());
+                            }
+                        }
+                    },
+                }
+            }
+        }
+        false
+    }
+}
+
+/// The button pushed again and again, one module per line of the map.
+#[derive(Debug, Clone)]
+struct Pushes {
+    circuit: Circuit,
+    /// The modules in the order of the lines, the broadcaster first.
+    names: Vec<String>,
+    pushes: usize,
+}
+
+impl Pushes {
+    fn new(components: &HashMap<String, Component>) -> Self {
+        let circuit = Circuit::new(components);
+        let mut names = circuit.components.keys().cloned().collect::<Vec<_>>();
+        names.sort_by_key(|name| (name != "broadcaster", name.clone()));
+        Self {
+            circuit,
+            names,
+            pushes: 0,
+        }
+    }
+
+    /// The last pulse `conn` carried, low when none went through it yet.
+    fn last_pulse(&self, conn: &Connection) -> Pulse {
+        self.circuit
+            .outputs
+            .get(&conn.from)
+            .and_then(|outputs| outputs.iter().find(|(c, _)| c == conn))
+            .map_or(Pulse::Low, |(_, pulse)| *pulse)
+    }
+
+    fn line(&self, name: &str) -> String {
+        let component = &self.circuit.components[name];
+        let (sign, state) = match component {
+            Component::Broadcaster(_) => ("", String::new()),
+            Component::FlipFlop(flip_flop) => ("%", format!("{:?}", flip_flop.state)),
+            Component::Conjunction(conjonction) => {
+                let high = conjonction
+                    .input_connections
+                    .iter()
+                    .filter(|conn| self.last_pulse(conn) == Pulse::High)
+                    .count();
+                (
+                    "&",
+                    format!("{}/{}", high, conjonction.input_connections.len()),
+                )
+            }
+        };
+        let dests = component
+            .output_connections()
+            .iter()
+            .map(|conn| conn.to.as_str())
+            .collect::<Vec<_>>();
+        let name = format!("{}{}", sign, name);
+        format!("{:<12} {:>5} -> {}", name, state, dests.join(", "))
+    }
+}
+
+impl Simulation for Pushes {
+    fn status(&self) -> String {
+        let counter = &self.circuit.pulse_counter;
+        format!(
+            "{} pushes, {} low and {} high pulses",
+            self.pushes, counter.low, counter.high
+        )
+    }
+
+    fn step(&mut self) -> bool {
+        self.circuit.push_button(|_, _| false);
+        self.pushes += 1;
+        true
+    }
+
+    fn map(&self) -> Grid<char> {
+        let lines = self
+            .names
+            .iter()
+            .map(|name| self.line(name))
+            .collect::<Vec<_>>();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(1);
+        let cells = lines
+            .iter()
+            .flat_map(|line| line.chars().chain(std::iter::repeat(' ')).take(width))
+            .collect();
+        Grid::from_vec(width, cells)
+    }
+
+    /// Flip-flops that are on, and conjunctions that remember only high
+    /// pulses and so send low ones.
+    fn highlights(&self) -> Vec<(Coord, Rgb)> {
+        self.names
+            .iter()
+            .enumerate()
+            .filter_map(|(y, name)| match &self.circuit.components[name] {
+                Component::FlipFlop(flip_flop) if flip_flop.state == State::On => {
+                    Some((Coord::new(0, y as isize), Rgb::YELLOW))
+                }
+                Component::Conjunction(conjonction)
+                    if conjonction
+                        .input_connections
+                        .iter()
+                        .all(|conn| self.last_pulse(conn) == Pulse::High) =>
+                {
+                    Some((Coord::new(0, y as isize), Rgb::GREEN))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn inspect(&self, coord: Coord) -> Option<String> {
+        let name = self.names.get(usize::try_from(coord.y).ok()?)?;
+        let component = &self.circuit.components[name];
+        let dests = component
+            .output_connections()
+            .iter()
+            .map(|conn| conn.to.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Some(match component {
+            Component::Broadcaster(_) => format!("{}: sends to {}", name, dests),
+            Component::FlipFlop(flip_flop) => format!(
+                "{}: flip-flop {:?}, sends to {}",
+                name, flip_flop.state, dests
+            ),
+            Component::Conjunction(conjonction) => {
+                let mut memory = conjonction
+                    .input_connections
+                    .iter()
+                    .map(|conn| format!("{} {:?}", conn.from, self.last_pulse(conn)))
+                    .collect::<Vec<_>>();
+                memory.sort();
+                format!(
+                    "{}: conjunction remembering {}, sends to {}",
+                    name,
+                    memory.join(", "),
+                    dests
+                )
+            }
+        })
+    }
+}
+
+/// The conjunction sending to rx and the conjunctions sending to it, `None`
+/// when rx is not wired that way. rx gets a low pulse once they all sent a
+/// high one to the conjunction during the same push.
+fn feeders(components: &HashMap<String, Component>) -> Option<(String, Vec<String>)> {
+    let sending_to = |to: &str| {
+        components
+            .iter()
+            .filter(move |(_, component)| {
+                component
+                    .output_connections()
+                    .iter()
+                    .any(|conn| conn.to == to)
+            })
+            .collect::<Vec<_>>()
+    };
+    let [(last, Component::Conjunction(_))] = sending_to("rx")[..] else {
+        return None;
+    };
+    let mut feeders = sending_to(last)
+        .into_iter()
+        .map(|(name, component)| match component {
+            Component::Conjunction(_) => Some(name.clone()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    if feeders.is_empty() {
+        return None;
+    }
+    feeders.sort();
+    Some((last.clone(), feeders))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "feeders",
+        default: "[]",
+        doc: "The conjunctions sending to the one before rx, part 2 takes the lcm of their cycles. Found in the circuit when empty",
+        check: params::any,
+    }];
+
+    fn check_params(params: &Values) -> Result<(), String> {
+        let feeders = params.get::<Vec<String>>("feeders")?;
+        for (i, name) in feeders.iter().enumerate() {
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(format!(
+                    "The param feeders of day 20 must name modules, not {:?}",
+                    name
+                ));
+            }
+            if feeders[..i].contains(name) {
+                return Err(format!("The param feeders of day 20 names {} twice", name));
+            }
+        }
+        Ok(())
+    }
+
+    type Model = HashMap<String, Component>;
+    type Answer1 = usize;
+    type Answer2 = Maybe<usize>;
+
+    fn parse(input: &str) -> Result<HashMap<String, Component>, ParseError> {
+        parse_input(input, parse)
+    }
+
+    fn part1(components: &HashMap<String, Component>) -> usize {
+        let mut circuit = Circuit::new(components);
+        for _ in 0..1000 {
+            circuit.push_button(|_, _| false);
+        }
+        log::debug!("pulse_counter = {:?}", circuit.pulse_counter);
+        checked::mul(circuit.pulse_counter.low, circuit.pulse_counter.high)
+    }
+
+    fn part2(components: &HashMap<String, Component>) -> Maybe<usize> {
+        // rx low means mf(inv) high.
+        // mf(inv) high means all inputs connected to mf are low.
+        // &sh -> &mf -> rx
+        // &mz -> &mf -> rx
+        // &bh -> &mf -> rx
+        // &jf -> &mf -> rx
+        let Some((last, mut feeders)) = feeders(components) else {
+            log::warn!("rx is not sent to by a conjunction of conjunctions");
+            return Maybe(None);
+        };
+        let set = params::get::<Self, Vec<String>>("feeders").expect("checked when loaded");
+        if let Some(name) = set.iter().find(|name| !feeders.contains(name)) {
+            log::warn!("{} is not a conjunction sending to {}", name, last);
+            return Maybe(None);
+        }
+        if !set.is_empty() {
+            feeders = set;
+        }
+        log::debug!("feeders of {} = {:?}", last, feeders);
+
+        let mut circuit = Circuit::new(components);
+        let mut lcms: HashMap<String, usize> = HashMap::new();
+
+        // Count the number of time the button is pressed
+        for counter in 1.. {
+            let done = circuit.push_button(|conn, pulse| {
+                if conn.to == last && pulse == Pulse::High && feeders.contains(&conn.from) {
+                    lcms.entry(conn.from.clone()).or_insert(counter);
+                }
+                lcms.len() == feeders.len()
+            });
+            if done {
+                break;
+            }
+        }
+        log::debug!("pulse_counter = {:?}", circuit.pulse_counter);
+        log::debug!("lcms = {:?}", lcms);
+        stats::record("lcm_inputs", &lcms);
+
+        Maybe(Some(
+            lcm_all(lcms.values().copied()).expect("the cycle lengths overflow their lcm"),
+        ))
+    }
+}
+
+impl Simulate for Day20 {
+    fn simulation(components: &HashMap<String, Component>) -> Box<dyn Simulation> {
+        Box::new(Pushes::new(components))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day20>();
+    }
+
+    #[test]
+    fn test_simulation() {
+        let components = Day20::parse(include_str!("../fixtures/example_2.txt")).unwrap();
+        let mut simulation = Day20::simulation(&components);
+        assert!(simulation.step());
+        assert_eq!(simulation.status(), "1 pushes, 4 low and 4 high pulses");
+        let map = simulation.map().to_string();
+        assert_eq!(
+            map.lines().map(str::trim_end).collect::<Vec<_>>(),
+            vec![
+                "broadcaster        -> a",
+                "%a              On -> inv, con",
+                "%b              On -> con",
+                "&con           2/2 -> output",
+                "&inv           1/1 -> b",
+            ]
+        );
+        assert_eq!(
+            simulation.inspect(Coord::new(3, 3)),
+            Some("con: conjunction remembering a High, b High, sends to output".to_string())
+        );
+        assert_eq!(
+            simulation.highlights(),
+            vec![
+                (Coord::new(0, 1), Rgb::YELLOW),
+                (Coord::new(0, 2), Rgb::YELLOW),
+                (Coord::new(0, 3), Rgb::GREEN),
+                (Coord::new(0, 4), Rgb::GREEN),
+            ]
+        );
+        for _ in 1..1000 {
+            simulation.step();
+        }
+        assert!(simulation
+            .status()
+            .starts_with("1000 pushes, 4250 low and 2750 high pulses"));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = indoc!(
+            "
+            broadcaster -> a, b
+            %a -> fa
+            %b -> x
+            %x -> fb
+            &fa -> mf
+            &fb -> mf
+            &mf -> rx
+            "
+        );
+        let components = Day20::parse(input).unwrap();
+        assert_eq!(
+            feeders(&components),
+            Some(("mf".to_string(), vec!["fa".to_string(), "fb".to_string()]))
+        );
+        assert_eq!(Day20::part2(&components), Maybe(Some(4)));
+
+        // Without rx there is no answer to wait for
+        let components = Day20::parse(include_str!("../fixtures/example_2.txt")).unwrap();
+        assert_eq!(feeders(&components), None);
+        assert_eq!(Day20::part2(&components).to_string(), "none");
+    }
+
+    #[test]
+    fn test_check_params() {
+        let check = |content: &str| {
+            params::Config::parse(content)
+                .unwrap()
+                .check(20, Day20::PARAMS, Day20::check_params)
+        };
+        assert_eq!(check("[day_20]\nfeeders = []"), Ok(()));
+        assert_eq!(check("[day_20]\nfeeders = [\"sh\", \"mz\"]"), Ok(()));
+        assert_eq!(
+            check("[day_20]\nfeeders = [\"sh\", \"mz\", \"sh\"]"),
+            Err("The param feeders of day 20 names sh twice".to_string())
+        );
+        assert_eq!(
+            check("[day_20]\nfeeders = [\"&sh\"]"),
+            Err("The param feeders of day 20 must name modules, not \"&sh\"".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = indoc!(
+            "
+            broadcaster -> a
+            !a -> b
+            "
+        );
+        assert_eq!(
+            Day20::parse(input).unwrap_err(),
+            ParseError::new(2, 1, "!a -> b", "a component (broadcaster, %name or &name)")
+        );
+        assert_eq!(
+            Day20::parse("").unwrap_err(),
+            ParseError::new(1, 1, "", "a broadcaster")
+        );
+        assert_eq!(
+            Day20::parse("%a -> b\n%b -> a\n").unwrap_err(),
+            ParseError::new(1, 1, "%a -> b", "a broadcaster")
+        );
+        assert_eq!(
+            Day20::parse("broadcaster -> a\n%a -> b\n&a -> b\n%b -> a\n").unwrap_err(),
+            ParseError::new(3, 1, "&a -> b", "a module defined once")
+        );
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_20::Day20;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day20>()
+}
@@ -0,0 +1,246 @@
+//! Day 21: Step Counter
+//!
+//! The model is the garden map, with the elf starting on `S`,
+//! [`reachable_plots`] solves part 1 for any number of steps.
+
+use std::collections::HashSet;
+
+use nom::error::context;
+
+use aoc_common::{
+    grid::char_grid_with_start,
+    params::{self, Param},
+    parse::{parse_input, IResult, ParseError},
+    render::{Frames, Image, Palette, Rgb},
+    search::bfs,
+    simulation::{Simulate, Simulation},
+    trace_map, Coord, Grid, Solution, TextMap,
+};
+
+fn parse(input: &str) -> IResult<&str, Data> {
+    let (input, grid) = context(
+        "a garden plot ., a rock # or the start S",
+        char_grid_with_start(".#S", 'S', "a start S in the garden", "a single start S"),
+    )(input)?;
+    Ok((input, Data { grid }))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Data {
+    pub grid: Grid<char>,
+}
+
+/// The plots the elf can be on after each step, drawn as `O` like in the
+/// puzzle.
+#[derive(Debug, Clone)]
+struct Walk {
+    grid: Grid<char>,
+    reached: HashSet<Coord>,
+    steps: usize,
+}
+
+impl Simulation for Walk {
+    fn status(&self) -> String {
+        format!("step {}, {} plots reached", self.steps, self.reached.len())
+    }
+
+    fn step(&mut self) -> bool {
+        self.reached = self
+            .reached
+            .iter()
+            .flat_map(|c| self.grid.neighbours(*c))
+            .filter(|(_, tile)| **tile != '#')
+            .map(|(c, _)| c)
+            .collect();
+        self.steps += 1;
+        true
+    }
+
+    fn map(&self) -> Grid<char> {
+        let mut grid = self.grid.clone();
+        for c in &self.reached {
+            grid[*c] = 'O';
+        }
+        grid
+    }
+
+    fn inspect(&self, coord: Coord) -> Option<String> {
+        let description = match self.grid.get(coord)? {
+            '#' => "rock",
+            'S' => "starting plot",
+            _ => "garden plot",
+        };
+        let reached = if self.reached.contains(&coord) {
+            format!(", reached in {} steps", self.steps)
+        } else {
+            String::new()
+        };
+        Some(format!(
+            "({}, {}) {}{}",
+            coord.x, coord.y, description, reached
+        ))
+    }
+}
+
+fn garden_map(grid: &Grid<char>) -> TextMap {
+    TextMap::new(grid.width(), grid.height())
+        .layer(grid.iter().map(|(c, v)| (c.x as usize, c.y as usize, *v)))
+}
+
+/// Number of garden plots the elf can end on after exactly `steps` steps.
+pub fn reachable_plots(data: &Data, steps: usize) -> usize {
+    log::debug!("data = {:?}", data);
+
+    trace_map("Garden:", || garden_map(&data.grid));
+
+    let start_pos = data.grid.position(|c| *c == 'S').unwrap();
+    let garden = &data.grid;
+    let search = bfs(
+        [start_pos],
+        |pos| {
+            garden
+                .neighbours(*pos)
+                .filter_map(|o| if *o.1 == '#' { None } else { Some(o.0) })
+        },
+        |_| false,
+    );
+
+    // Stepping back and forth wastes two steps, so the walk can end on
+    // any plot reached in at most `steps` steps with the same parity
+    let positions = search
+        .distances
+        .iter()
+        .filter(|(_, d)| **d <= steps && **d % 2 == steps % 2)
+        .map(|(c, _)| *c)
+        .collect::<Vec<Coord>>();
+
+    // One frame per step: the plots the walk can end on, the frontier
+    // it just reached in red
+    if let Some(mut frames) = Frames::from_env("day_21") {
+        for step in 0..=steps {
+            let reachable = search
+                .distances
+                .iter()
+                .filter(|(_, d)| **d <= step && **d % 2 == step % 2);
+            frames.record(
+                &Image::new(garden, &Palette::default())
+                    .highlight(reachable.map(|(c, _)| *c), Rgb::YELLOW)
+                    .highlight(
+                        search
+                            .distances
+                            .iter()
+                            .filter(|(_, d)| **d == step)
+                            .map(|(c, _)| *c),
+                        Rgb::RED,
+                    ),
+            );
+        }
+    }
+
+    trace_map("Plots reached:", || {
+        garden_map(&data.grid).layer(positions.iter().map(|c| (c.x as usize, c.y as usize, 'O')))
+    });
+
+    positions.len()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "steps",
+        default: "64",
+        doc: "Steps the elf walks in part 1",
+        check: params::at_least::<0>,
+    }];
+
+    type Model = Data;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Data, ParseError> {
+        parse_input(input, parse)
+    }
+
+    fn part1(data: &Data) -> usize {
+        let steps = params::get::<Self, usize>("steps").expect("checked when loaded");
+        reachable_plots(data, steps)
+    }
+
+    fn part2(_data: &Data) -> usize {
+        todo!()
+    }
+}
+
+impl Simulate for Day21 {
+    fn simulation(data: &Data) -> Box<dyn Simulation> {
+        Box::new(Walk {
+            grid: data.grid.clone(),
+            reached: data.grid.position(|c| *c == 'S').into_iter().collect(),
+            steps: 0,
+        })
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day21>();
+    }
+
+    #[test]
+    fn test_simulation() {
+        let model = Day21::parse(include_str!("../fixtures/example_1.txt")).unwrap();
+        let mut simulation = Day21::simulation(&model);
+        assert!(simulation.step());
+        assert_eq!(simulation.status(), "step 1, 2 plots reached");
+        assert_eq!(
+            simulation.inspect(Coord::new(4, 5)),
+            Some("(4, 5) garden plot, reached in 1 steps".to_string())
+        );
+        for _ in 1..6 {
+            simulation.step();
+        }
+        assert_eq!(simulation.status(), "step 6, 16 plots reached");
+        assert_eq!(
+            simulation.map().iter().filter(|(_, c)| **c == 'O').count(),
+            16
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day21::parse("..#\n.#.\n").unwrap_err(),
+            ParseError::new(1, 1, "..#", "a start S in the garden")
+        );
+        assert_eq!(
+            Day21::parse(".S#\n.#S\n").unwrap_err(),
+            ParseError::new(2, 3, ".#S", "a single start S")
+        );
+        assert_eq!(
+            Day21::parse(".S#\n.O.\n").unwrap_err(),
+            ParseError::new(2, 2, ".O.", "a garden plot ., a rock # or the start S")
+        );
+    }
+
+    #[test]
+    #[ignore = "part 2 is not implemented yet"]
+    fn test_run1_part2() {
+        let model = Day21::parse(include_str!("../fixtures/example_1.txt")).unwrap();
+        assert_eq!(Day21::part2(&model), 102);
+    }
+}
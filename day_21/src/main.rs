@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day_21::Day21;
+
+fn main() -> ExitCode {
+    aoc_common::solution::main::<Day21>()
+}